@@ -0,0 +1,68 @@
+//! Compares [`Slide::find_byte`] and [`Slide::find_any_byte`] against the generic
+//! [`Slide::find`] on large buffers.
+//!
+//! Run with `cargo bench --bench find_byte`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use slide::slide::Slide;
+
+/// The length of the haystack, with the only match at the very end.
+const LEN: usize = 1 << 20;
+
+/// The amount of times each search is repeated.
+const ITERS: u32 = 200;
+
+/// Time `f` over [`ITERS`] runs, returning the average duration of a run.
+fn time<F>(mut f: F) -> Duration
+where
+    F: FnMut() -> Option<usize>,
+{
+    // NOTE: Warm up the caches first.
+    black_box(f());
+
+    let start = Instant::now();
+
+    for _ in 0..ITERS {
+        black_box(f());
+    }
+
+    start.elapsed() / ITERS
+}
+
+/// Print the result of a benchmark, including its throughput.
+fn report(
+    name: &str,
+    elapsed: Duration,
+) {
+    let gib_per_sec = LEN as f64 / elapsed.as_secs_f64() / (1u64 << 30) as f64;
+
+    println!("{name:<24} {elapsed:>12?} {gib_per_sec:>8.2} GiB/s");
+}
+
+fn main() {
+    let mut haystack = vec![b'a'; LEN];
+    *haystack.last_mut().unwrap() = b'\n';
+
+    let haystack = black_box(&haystack[..]);
+
+    report(
+        "find (closure)",
+        time(|| Slide::new(haystack).find(|&byte| byte == b'\n')),
+    );
+    report(
+        "find_byte",
+        time(|| Slide::new(haystack).find_byte(black_box(b'\n'))),
+    );
+    report(
+        "find (closure, 3 bytes)",
+        time(|| Slide::new(haystack).find(|&byte| matches!(byte, b'\n' | b'\r' | b'\0'))),
+    );
+    report(
+        "find_any_byte (3 bytes)",
+        time(|| Slide::new(haystack).find_any_byte(black_box(b"\n\r\0"))),
+    );
+}
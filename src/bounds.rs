@@ -9,9 +9,21 @@ pub(crate) mod private;
 /// Module for the [`SliceRange`] type.
 mod slice_range;
 
+/// Module for the [`Strided`] iterator.
+mod strided;
+
+/// Module for the [`ChunkRanges`] iterator.
+mod chunk_ranges;
+
 #[doc(inline)]
 pub use slice_range::{SliceRange, SliceRangeError};
 
+#[doc(inline)]
+pub use strided::Strided;
+
+#[doc(inline)]
+pub use chunk_ranges::ChunkRanges;
+
 /// A marker trait for types that can be used in `const` to create a [`SliceRange`].
 ///
 /// # Safety
@@ -89,83 +101,358 @@ where
     to_bounds(bounds.as_ref())
 }
 
-// /// Returns whether the provided `value` is contained within `bounds`.
-// #[inline(always)]
-// #[must_use]
-// pub const fn contains<B: SliceBounds + ?Sized>(bounds: &B, value: usize) -> bool {
-//     let (start, end) = as_bounds(bounds);
-
-//     (match start {
-//         Bound::Included(&start) => start <= value,
-//         Bound::Excluded(&start) => start < value,
-//         Bound::Unbounded => true,
-//     }) && (match end {
-//         Bound::Included(&end) => value <= end,
-//         Bound::Excluded(&end) => value < end,
-//         Bound::Unbounded => true,
-//     })
-// }
-
-// /// Returns whether the provided bounds are empty.
-// #[inline(always)]
-// #[must_use]
-// pub const fn is_empty<B: SliceBounds + ?Sized>(bounds: &B) -> bool {
-//     match as_bounds(bounds) {
-//         // `..end` | `start..` | `..`
-//         (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
-//         // `start..end`
-//         (Bound::Included(&start), Bound::Excluded(&end)) => start >= end,
-//         // `start - 1..end`
-//         (Bound::Excluded(&start), Bound::Excluded(&end)) => start >= end,
-//         // `start - 1..=end`
-//         (Bound::Excluded(&start), Bound::Included(&end)) => start >= end,
-//         // `start..=end`.
-//         (Bound::Included(&start), Bound::Included(&end)) => start > end,
-//     }
-// }
-
-// /// Returns the intersection of `a` and `b`.
-// #[inline(always)]
-// #[must_use]
-// pub const fn intersection<A, B>(a: &A, b: &B) -> (Bound<usize>, Bound<usize>)
-// where
-//     A: SliceBounds + ?Sized,
-//     B: SliceBounds + ?Sized,
-// {
-//     let (a_start, a_end) = to_bounds(a);
-//     let (b_start, b_end) = to_bounds(b);
-
-//     let start = match (a_start, b_start) {
-//         (Bound::Included(a), Bound::Included(b)) => Bound::Included(if a >= b { a } else { b }),
-//         (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(if a >= b { a } else { b }),
-//         (Bound::Unbounded, Bound::Unbounded) => Bound::Unbounded,
-
-//         (x, Bound::Unbounded) | (Bound::Unbounded, x) => x,
-
-//         (Bound::Included(i), Bound::Excluded(e)) | (Bound::Excluded(e), Bound::Included(i)) => {
-//             if i > e {
-//                 Bound::Included(i)
-//             } else {
-//                 Bound::Excluded(e)
-//             }
-//         }
-//     };
-
-//     let end = match (a_end, b_end) {
-//         (Bound::Included(a), Bound::Included(b)) => Bound::Included(if a <= b { a } else { b }),
-//         (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(if a <= b { a } else { b }),
-//         (Bound::Unbounded, Bound::Unbounded) => Bound::Unbounded,
-
-//         (x, Bound::Unbounded) | (Bound::Unbounded, x) => x,
-
-//         (Bound::Included(i), Bound::Excluded(e)) | (Bound::Excluded(e), Bound::Included(i)) => {
-//             if i < e {
-//                 Bound::Included(i)
-//             } else {
-//                 Bound::Excluded(e)
-//             }
-//         }
-//     };
-
-//     (start, end)
-// }
+/// Returns whether the provided `value` is contained within `bounds`.
+#[inline(always)]
+#[must_use]
+pub const fn contains<B>(
+    bounds: &B,
+    value: usize,
+) -> bool
+where
+    B: SliceBounds + ?Sized,
+{
+    let (start, end) = as_bounds(bounds);
+
+    (match start {
+        Bound::Included(&start) => start <= value,
+        Bound::Excluded(&start) => start < value,
+        Bound::Unbounded => true,
+    }) && (match end {
+        Bound::Included(&end) => value <= end,
+        Bound::Excluded(&end) => value < end,
+        Bound::Unbounded => true,
+    })
+}
+
+/// Returns whether the provided bounds are empty.
+#[inline(always)]
+#[must_use]
+pub const fn is_empty<B>(bounds: &B) -> bool
+where
+    B: SliceBounds + ?Sized,
+{
+    match as_bounds(bounds) {
+        // `..0`
+        (Bound::Unbounded, Bound::Excluded(&end)) => end == 0,
+        // `usize::MAX + 1..`
+        (Bound::Excluded(&start), Bound::Unbounded) => start == usize::MAX,
+        // `..=end` | `start..` | `..`
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+        // `start..end`
+        (Bound::Included(&start), Bound::Excluded(&end)) => start >= end,
+        // `start + 1..end`
+        (Bound::Excluded(&start), Bound::Excluded(&end)) => start.saturating_add(1) >= end,
+        // `start + 1..=end`
+        (Bound::Excluded(&start), Bound::Included(&end)) => start >= end,
+        // `start..=end`.
+        (Bound::Included(&start), Bound::Included(&end)) => start > end,
+    }
+}
+
+/// Returns the intersection of `a` and `b`, the bounds containing only the values that are
+/// contained within both.
+///
+/// If `a` and `b` are disjoint, then the returned bounds are empty.
+#[inline(always)]
+#[must_use]
+pub const fn intersection<A, B>(
+    a: &A,
+    b: &B,
+) -> (Bound<usize>, Bound<usize>)
+where
+    A: SliceBounds + ?Sized,
+    B: SliceBounds + ?Sized,
+{
+    let (a_start, a_end) = to_bounds(a);
+    let (b_start, b_end) = to_bounds(b);
+
+    let start = match (a_start, b_start) {
+        (Bound::Included(a), Bound::Included(b)) => Bound::Included(if a >= b { a } else { b }),
+        (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(if a >= b { a } else { b }),
+        (Bound::Unbounded, Bound::Unbounded) => Bound::Unbounded,
+
+        (x, Bound::Unbounded) | (Bound::Unbounded, x) => x,
+
+        (Bound::Included(i), Bound::Excluded(e)) | (Bound::Excluded(e), Bound::Included(i)) => {
+            if i > e {
+                Bound::Included(i)
+            } else {
+                Bound::Excluded(e)
+            }
+        }
+    };
+
+    let end = match (a_end, b_end) {
+        (Bound::Included(a), Bound::Included(b)) => Bound::Included(if a <= b { a } else { b }),
+        (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(if a <= b { a } else { b }),
+        (Bound::Unbounded, Bound::Unbounded) => Bound::Unbounded,
+
+        (x, Bound::Unbounded) | (Bound::Unbounded, x) => x,
+
+        (Bound::Included(i), Bound::Excluded(e)) | (Bound::Excluded(e), Bound::Included(i)) => {
+            if i < e {
+                Bound::Included(i)
+            } else {
+                Bound::Excluded(e)
+            }
+        }
+    };
+
+    (start, end)
+}
+
+/// Returns the union of `a` and `b`, the smallest bounds containing every value that is
+/// contained within either.
+///
+/// If `a` and `b` are disjoint, then the returned bounds also contain the gap between them.
+#[inline(always)]
+#[must_use]
+pub const fn union<A, B>(
+    a: &A,
+    b: &B,
+) -> (Bound<usize>, Bound<usize>)
+where
+    A: SliceBounds + ?Sized,
+    B: SliceBounds + ?Sized,
+{
+    let (a_start, a_end) = to_bounds(a);
+    let (b_start, b_end) = to_bounds(b);
+
+    let start = match (a_start, b_start) {
+        (Bound::Included(a), Bound::Included(b)) => Bound::Included(if a <= b { a } else { b }),
+        (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(if a <= b { a } else { b }),
+
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => Bound::Unbounded,
+
+        (Bound::Included(i), Bound::Excluded(e)) | (Bound::Excluded(e), Bound::Included(i)) => {
+            if i <= e {
+                Bound::Included(i)
+            } else {
+                Bound::Excluded(e)
+            }
+        }
+    };
+
+    let end = match (a_end, b_end) {
+        (Bound::Included(a), Bound::Included(b)) => Bound::Included(if a >= b { a } else { b }),
+        (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(if a >= b { a } else { b }),
+
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => Bound::Unbounded,
+
+        (Bound::Included(i), Bound::Excluded(e)) | (Bound::Excluded(e), Bound::Included(i)) => {
+            if i >= e {
+                Bound::Included(i)
+            } else {
+                Bound::Excluded(e)
+            }
+        }
+    };
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+
+    #[test]
+    fn contains_every_bound() {
+        // `start..=end`
+        assert!(!contains(&(Included(2), Included(5)), 1));
+        assert!(contains(&(Included(2), Included(5)), 2));
+        assert!(contains(&(Included(2), Included(5)), 5));
+        assert!(!contains(&(Included(2), Included(5)), 6));
+
+        // `start..end`
+        assert!(contains(&(Included(2), Excluded(5)), 2));
+        assert!(contains(&(Included(2), Excluded(5)), 4));
+        assert!(!contains(&(Included(2), Excluded(5)), 5));
+
+        // `start..`
+        assert!(!contains(&(Included(2), Unbounded), 1));
+        assert!(contains(&(Included(2), Unbounded), 2));
+        assert!(contains(&(Included(2), Unbounded), usize::MAX));
+
+        // `start + 1..=end`
+        assert!(!contains(&(Excluded(2), Included(5)), 2));
+        assert!(contains(&(Excluded(2), Included(5)), 3));
+        assert!(contains(&(Excluded(2), Included(5)), 5));
+        assert!(!contains(&(Excluded(2), Included(5)), 6));
+
+        // `start + 1..end`
+        assert!(!contains(&(Excluded(2), Excluded(5)), 2));
+        assert!(contains(&(Excluded(2), Excluded(5)), 3));
+        assert!(contains(&(Excluded(2), Excluded(5)), 4));
+        assert!(!contains(&(Excluded(2), Excluded(5)), 5));
+
+        // `start + 1..`
+        assert!(!contains(&(Excluded(2), Unbounded), 2));
+        assert!(contains(&(Excluded(2), Unbounded), 3));
+        assert!(!contains(&(Excluded(usize::MAX), Unbounded), usize::MAX));
+
+        // `..=end`
+        assert!(contains(&(Unbounded, Included(5)), 0));
+        assert!(contains(&(Unbounded, Included(5)), 5));
+        assert!(!contains(&(Unbounded, Included(5)), 6));
+
+        // `..end`
+        assert!(contains(&(Unbounded, Excluded(5)), 0));
+        assert!(contains(&(Unbounded, Excluded(5)), 4));
+        assert!(!contains(&(Unbounded, Excluded(5)), 5));
+        assert!(!contains(&(Unbounded, Excluded(0)), 0));
+
+        // `..`
+        assert!(contains(&(Unbounded::<usize>, Unbounded), 0));
+        assert!(contains(&(Unbounded::<usize>, Unbounded), usize::MAX));
+    }
+
+    #[test]
+    fn contains_range_types() {
+        assert!(contains(&(2..5), 2));
+        assert!(!contains(&(2..5), 5));
+        assert!(contains(&(..5), 0));
+        assert!(contains(&(..=5), 5));
+        assert!(contains(&(2..), usize::MAX));
+        assert!(contains(&.., 0));
+        assert!(!contains(&SliceRange::new(2, 5), 1));
+    }
+
+    #[test]
+    fn contains_const() {
+        const { assert!(contains(&(2..5), 4)) };
+        const { assert!(!contains(&(Excluded(2), Included(5)), 2)) };
+    }
+
+    #[test]
+    fn is_empty_edges() {
+        assert!(is_empty(&(5..5)));
+        assert!(!is_empty(&(4..5)));
+        assert!(is_empty(&(Excluded(5), Included(5))));
+        assert!(!is_empty(&(Excluded(4), Included(5))));
+        assert!(is_empty(&(Excluded(4), Excluded(5))));
+        assert!(!is_empty(&(Excluded(3), Excluded(5))));
+        assert!(is_empty(&(Excluded(usize::MAX), Excluded(usize::MAX))));
+        assert!(is_empty(&(Included(6), Included(5))));
+        assert!(!is_empty(&(Included(5), Included(5))));
+        assert!(is_empty(&..0));
+        assert!(!is_empty(&..1));
+        assert!(!is_empty(&..=0));
+        assert!(is_empty(&(Excluded(usize::MAX), Unbounded)));
+        assert!(!is_empty(&(Excluded(usize::MAX - 1), Unbounded)));
+        assert!(!is_empty(&(usize::MAX..)));
+        assert!(!is_empty(&..));
+    }
+
+    #[test]
+    fn is_empty_const() {
+        const { assert!(is_empty(&(5..5))) };
+        const { assert!(!is_empty(&..=0)) };
+    }
+
+    #[test]
+    fn intersection_overlapping() {
+        assert_eq!(intersection(&..10, &(5..)), (Included(5), Excluded(10)));
+        assert_eq!(
+            intersection(&(2..8), &(Included(4), Included(9))),
+            (Included(4), Excluded(8))
+        );
+        assert_eq!(
+            intersection(&(Included(2), Included(8)), &(4..9)),
+            (Included(4), Included(8))
+        );
+        assert_eq!(intersection(&.., &..), (Unbounded, Unbounded));
+        assert_eq!(intersection(&(3..), &..), (Included(3), Unbounded));
+    }
+
+    #[test]
+    fn intersection_mixed_bounds() {
+        // NOTE: An excluded start of `2` and an included start of `3` are equivalent.
+        assert_eq!(
+            intersection(&(Excluded(2), Unbounded), &(3..)),
+            (Included(3), Unbounded)
+        );
+        assert_eq!(
+            intersection(&(Excluded(2), Unbounded), &(2..)),
+            (Excluded(2), Unbounded)
+        );
+        assert_eq!(
+            intersection(&(Excluded(1), Unbounded), &(3..)),
+            (Included(3), Unbounded)
+        );
+        assert_eq!(intersection(&..5, &..=4), (Unbounded, Included(4)));
+        assert_eq!(intersection(&..5, &..=5), (Unbounded, Excluded(5)));
+        assert_eq!(intersection(&..5, &..=3), (Unbounded, Included(3)));
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        let cases = [
+            intersection(&(0..5), &(5..10)),
+            intersection(&(0..5), &(7..10)),
+            intersection(&(7..10), &(0..5)),
+            intersection(&..=3, &(Excluded(3), Unbounded)),
+        ];
+
+        for bounds in cases {
+            assert!(is_empty(&bounds), "{bounds:?} is not empty");
+
+            let start = match bounds.0 {
+                Included(start) => start,
+                Excluded(start) => start + 1,
+                Unbounded => 0,
+            };
+            let end = match bounds.1 {
+                Included(end) => end + 1,
+                Excluded(end) => end,
+                Unbounded => usize::MAX,
+            };
+
+            assert!(start >= end, "{bounds:?}");
+        }
+    }
+
+    #[test]
+    fn union_bounds() {
+        assert_eq!(union(&(2..5), &(4..8)), (Included(2), Excluded(8)));
+        assert_eq!(union(&(0..2), &(6..8)), (Included(0), Excluded(8)));
+        assert_eq!(union(&(2..5), &..), (Unbounded, Unbounded));
+        assert_eq!(union(&(2..5), &(3..)), (Included(2), Unbounded));
+        assert_eq!(
+            union(&(Included(2), Included(5)), &(Included(3), Included(4))),
+            (Included(2), Included(5))
+        );
+        assert_eq!(
+            union(&(Excluded(2), Excluded(5)), &(Excluded(1), Excluded(4))),
+            (Excluded(1), Excluded(5))
+        );
+    }
+
+    #[test]
+    fn union_mixed_bounds() {
+        assert_eq!(
+            union(&(Excluded(2), Unbounded), &(2..)),
+            (Included(2), Unbounded)
+        );
+        assert_eq!(
+            union(&(Excluded(2), Unbounded), &(4..)),
+            (Excluded(2), Unbounded)
+        );
+        assert_eq!(
+            union(&(Excluded(2), Unbounded), &(1..)),
+            (Included(1), Unbounded)
+        );
+        assert_eq!(union(&..5, &..=5), (Unbounded, Included(5)));
+        assert_eq!(union(&..5, &..=3), (Unbounded, Excluded(5)));
+        assert_eq!(union(&..5, &..=7), (Unbounded, Included(7)));
+    }
+
+    #[test]
+    fn intersection_and_union_const() {
+        const I: (Bound<usize>, Bound<usize>) = intersection(&..10, &(5..));
+        const U: (Bound<usize>, Bound<usize>) = union(&(0..2), &(6..8));
+
+        assert_eq!(I, (Included(5), Excluded(10)));
+        assert_eq!(U, (Included(0), Excluded(8)));
+    }
+}
@@ -0,0 +1,145 @@
+use core::iter::FusedIterator;
+
+use crate::{bounds::SliceRange, util::min_usize};
+
+/// An iterator over successive, non-overlapping sub-ranges of a [`SliceRange`].
+///
+/// Every sub-range has a length of `size`, except for possibly the last, which may be
+/// shorter.
+///
+/// See [`SliceRange::chunk_ranges`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkRanges {
+    /// The part of the range that has yet to be yielded.
+    range: SliceRange,
+    /// The length of each sub-range.
+    size: usize,
+}
+
+impl ChunkRanges {
+    /// Create a new [`ChunkRanges`] iterator over `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn new(
+        range: SliceRange,
+        size: usize,
+    ) -> ChunkRanges {
+        assert!(size != 0, "`size == 0`");
+
+        ChunkRanges { range, size }
+    }
+
+    /// Returns the length of each sub-range.
+    #[inline(always)]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the part of the range that has yet to be yielded.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remainder(&self) -> SliceRange {
+        self.range
+    }
+}
+
+impl Iterator for ChunkRanges {
+    type Item = SliceRange;
+
+    #[inline]
+    fn next(&mut self) -> Option<SliceRange> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        let start = self.range.start();
+        let end = self.range.end();
+
+        // SAFETY: The chunk is no longer than the range, so `mid <= end`.
+        let mid = unsafe { start.unchecked_add(min_usize(self.size, self.range.len())) };
+
+        // SAFETY: We know that `start <= mid && mid <= end`.
+        self.range = unsafe { SliceRange::new_unchecked(mid, end) };
+
+        // SAFETY: We know that `start <= mid`.
+        Some(unsafe { SliceRange::new_unchecked(start, mid) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<SliceRange> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for ChunkRanges {
+    #[inline]
+    fn next_back(&mut self) -> Option<SliceRange> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        let start = self.range.start();
+        let end = self.range.end();
+
+        // NOTE: The last chunk holds whatever doesn't fit evenly.
+        let take = match self.range.len() % self.size {
+            0 => self.size,
+            rem => rem,
+        };
+
+        // SAFETY: The chunk is no longer than the range, so `mid >= start`.
+        let mid = unsafe { end.unchecked_sub(take) };
+
+        // SAFETY: We know that `start <= mid`.
+        self.range = unsafe { SliceRange::new_unchecked(start, mid) };
+
+        // SAFETY: We know that `mid <= end`.
+        Some(unsafe { SliceRange::new_unchecked(mid, end) })
+    }
+}
+
+impl ExactSizeIterator for ChunkRanges {
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len().div_ceil(self.size)
+    }
+}
+
+impl FusedIterator for ChunkRanges {}
+
+impl SliceRange {
+    /// Returns an iterator over successive, non-overlapping sub-ranges of this range,
+    /// each of length `size`.
+    ///
+    /// The sub-ranges cover the entire range, with the last one possibly being shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn chunk_ranges(
+        self,
+        size: usize,
+    ) -> ChunkRanges {
+        ChunkRanges::new(self, size)
+    }
+}
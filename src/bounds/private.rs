@@ -115,15 +115,16 @@ define_bounds! {
 
     /// The [`ops::RangeInclusive`] type.
     ///
-    /// Currently this type is disabled because we cannot,
-    /// from `const`, get the actual end bound of the range
-    /// in a manner consistent with [`ops::RangeBounds`].
+    /// We cannot, from `const`, observe whether the range has been exhausted
+    /// by iteration, so the exhaustion state is ignored and the range is always
+    /// treated as `start..=end`. An end of `usize::MAX` reports
+    /// [`SliceRangeError::EndOverflow`](crate::bounds::SliceRangeError::EndOverflow).
     ///
-    /// Until this is possible, we're choosing not to support this type.
-    #[cfg(false)]
+    /// Callers that need [`ops::RangeBounds`]-consistent handling of exhausted
+    /// ranges should use [`SliceRange::from_range_inclusive`] instead.
     RangeInclusive(range => ops::RangeInclusive<usize>) => (
         Bound::Included(range.start()),
-        Bound::Excluded(range.end()),
+        Bound::Included(range.end()),
     ),
     /// The [`ops::RangeTo`] type.
     RangeTo(range => ops::RangeTo<usize>) => (
@@ -152,3 +153,63 @@ define_bounds! {
         bound_ref(end),
     ),
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::{SliceRange, SliceRangeError, as_bounds};
+    use core::ops::Bound;
+
+    #[test]
+    fn range_inclusive_bounds() {
+        assert_eq!(
+            as_bounds(&(2..=5)),
+            (Bound::Included(&2), Bound::Included(&5))
+        );
+        assert_eq!(
+            SliceRange::try_from_slice_bounds(&(2..=5), 10),
+            Ok(SliceRange::new(2, 6))
+        );
+        assert_eq!(
+            SliceRange::try_from_slice_bounds(&(2..=9), 10),
+            Ok(SliceRange::new(2, 10))
+        );
+        assert_eq!(
+            SliceRange::try_from_slice_bounds(&(5..=5), 10),
+            Ok(SliceRange::new(5, 6))
+        );
+    }
+
+    #[test]
+    fn range_inclusive_end_overflow() {
+        assert_eq!(
+            SliceRange::try_from_slice_bounds(&(0..=usize::MAX), usize::MAX),
+            Err(SliceRangeError::EndOverflow)
+        );
+    }
+
+    #[test]
+    fn range_inclusive_const() {
+        const RANGE: Result<SliceRange, SliceRangeError> =
+            SliceRange::try_from_slice_bounds(&(2..=5), 10);
+
+        assert_eq!(RANGE, Ok(SliceRange::new(2, 6)));
+    }
+
+    #[test]
+    fn range_inclusive_exhausted() {
+        let mut range = 2..=3;
+
+        range.by_ref().for_each(drop);
+
+        // NOTE: The exhaustion state is ignored, so this is treated as `start()..=end()`,
+        //       whereas `RangeBounds` reports it as `start()..end()`.
+        assert_eq!(
+            SliceRange::try_from_slice_bounds(&range, 10),
+            Ok(SliceRange::new(3, 4))
+        );
+        assert_eq!(
+            SliceRange::from_range_inclusive(&range, 10),
+            Some(SliceRange::new(3, 3))
+        );
+    }
+}
@@ -29,7 +29,6 @@ pub struct SliceRange {
     end: usize,
 }
 
-#[allow(clippy::len_without_is_empty)]
 impl SliceRange {
     /// Attempt to create a new [`SliceRange`] from `start..end`.
     ///
@@ -98,6 +97,50 @@ impl SliceRange {
         SliceRange::new(0, end)
     }
 
+    /// Shift both endpoints of this range forward by `amount`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(range)` upon success.
+    ///
+    /// - `None` if the end would overflow.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn checked_shift_forward(
+        self,
+        amount: usize,
+    ) -> Option<SliceRange> {
+        match self.end().checked_add(amount) {
+            // SAFETY: Both endpoints are shifted by the same amount, and since the end
+            //         didn't overflow, the start can't either.
+            Some(end) => Some(unsafe { SliceRange::new_unchecked(self.start + amount, end) }),
+            None => None,
+        }
+    }
+
+    /// Shift both endpoints of this range backward by `amount`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(range)` upon success.
+    ///
+    /// - `None` if the start would underflow.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn checked_shift_backward(
+        self,
+        amount: usize,
+    ) -> Option<SliceRange> {
+        match self.start().checked_sub(amount) {
+            // SAFETY: Both endpoints are shifted by the same amount, and since the start
+            //         didn't underflow, the end can't either.
+            Some(start) => Some(unsafe { SliceRange::new_unchecked(start, self.end - amount) }),
+            None => None,
+        }
+    }
+
     /// Hint to the compiler that an [`SliceRange`] can only be created
     /// if `start <= end`.
     #[inline]
@@ -117,6 +160,111 @@ impl SliceRange {
         unsafe { self.end.unchecked_sub(self.start) }
     }
 
+    /// Returns whether the range is empty (`start == end`).
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether `index` is contained within the range (`start <= index < end`).
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn contains(
+        &self,
+        index: usize,
+    ) -> bool {
+        self.start() <= index && index < self.end()
+    }
+
+    /// Returns the overlapping region of `self` and `other`.
+    ///
+    /// If they are disjoint, then this returns an empty range positioned at the greater
+    /// of the two starts.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn intersection(
+        self,
+        other: SliceRange,
+    ) -> SliceRange {
+        let start = if self.start() >= other.start() {
+            self.start()
+        } else {
+            other.start()
+        };
+
+        let end = if self.end() <= other.end() {
+            self.end()
+        } else {
+            other.end()
+        };
+
+        let end = if end >= start { end } else { start };
+
+        // SAFETY: We just ensured that `start <= end`.
+        unsafe { SliceRange::new_unchecked(start, end) }
+    }
+
+    /// Split this range into two at `mid`, an offset relative to `start`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((start..start + mid, start + mid..end))` upon success.
+    ///
+    /// - `None` if `mid > self.len()`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_at(
+        self,
+        mid: usize,
+    ) -> Option<(SliceRange, SliceRange)> {
+        if mid > self.len() {
+            return None;
+        }
+
+        // SAFETY: We know that `mid <= len`, so `start + mid <= end`.
+        let index = unsafe { self.start.unchecked_add(mid) };
+
+        // SAFETY: We know that `start <= index <= end`.
+        unsafe {
+            Some((
+                SliceRange::new_unchecked(self.start, index),
+                SliceRange::new_unchecked(index, self.end),
+            ))
+        }
+    }
+
+    /// Split this range into two at `index`, an absolute position.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((start..index, index..end))` upon success.
+    ///
+    /// - `None` if `index < start` or `index > end`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_at_absolute(
+        self,
+        index: usize,
+    ) -> Option<(SliceRange, SliceRange)> {
+        if index < self.start || index > self.end {
+            return None;
+        }
+
+        // SAFETY: We know that `start <= index <= end`.
+        unsafe {
+            Some((
+                SliceRange::new_unchecked(self.start, index),
+                SliceRange::new_unchecked(index, self.end),
+            ))
+        }
+    }
+
     /// Get the start of the range.
     #[inline]
     #[must_use]
@@ -336,6 +484,28 @@ impl SliceRange {
         try_from_bounds((bounds.start_bound(), bounds.end_bound()), len).unwrap()
     }
 
+    /// Attempt to create a new [`SliceRange`] from an [`ops::RangeInclusive`].
+    ///
+    /// Unlike the `const` [`SliceBounds`] path, which cannot observe whether an inclusive
+    /// range has been exhausted and so always treats it as `start..=end`, this uses
+    /// [`RangeBounds::end_bound`] and is therefore correct for exhausted ranges too.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(range)` upon success.
+    ///
+    /// - `None` if the range is invalid or would not fit within `len`. See
+    ///   [`SliceRange::try_from_range_bounds`] for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn from_range_inclusive(
+        range: &ops::RangeInclusive<usize>,
+        len: usize,
+    ) -> Option<SliceRange> {
+        SliceRange::try_from_range_bounds(range, len).ok()
+    }
+
     /// Create a new [`SliceRange`] from something that implements [`SliceBounds`] without any checks.
     ///
     /// # Safety
@@ -463,6 +633,80 @@ impl Default for SliceRange {
     }
 }
 
+/// Shifts both endpoints forward.
+///
+/// # Panics
+///
+/// Panics if the end overflows. See [`SliceRange::checked_shift_forward`] for a
+/// fallible alternative.
+impl ops::Add<usize> for SliceRange {
+    type Output = SliceRange;
+
+    #[inline]
+    #[track_caller]
+    fn add(
+        self,
+        amount: usize,
+    ) -> SliceRange {
+        self.checked_shift_forward(amount)
+            .expect("shifting range forward overflowed")
+    }
+}
+
+/// Shifts both endpoints forward.
+///
+/// # Panics
+///
+/// Panics if the end overflows. See [`SliceRange::checked_shift_forward`] for a
+/// fallible alternative.
+impl ops::AddAssign<usize> for SliceRange {
+    #[inline]
+    #[track_caller]
+    fn add_assign(
+        &mut self,
+        amount: usize,
+    ) {
+        *self = *self + amount;
+    }
+}
+
+/// Shifts both endpoints backward.
+///
+/// # Panics
+///
+/// Panics if the start underflows. See [`SliceRange::checked_shift_backward`] for a
+/// fallible alternative.
+impl ops::Sub<usize> for SliceRange {
+    type Output = SliceRange;
+
+    #[inline]
+    #[track_caller]
+    fn sub(
+        self,
+        amount: usize,
+    ) -> SliceRange {
+        self.checked_shift_backward(amount)
+            .expect("shifting range backward underflowed")
+    }
+}
+
+/// Shifts both endpoints backward.
+///
+/// # Panics
+///
+/// Panics if the start underflows. See [`SliceRange::checked_shift_backward`] for a
+/// fallible alternative.
+impl ops::SubAssign<usize> for SliceRange {
+    #[inline]
+    #[track_caller]
+    fn sub_assign(
+        &mut self,
+        amount: usize,
+    ) {
+        *self = *self - amount;
+    }
+}
+
 impl RangeBounds<usize> for SliceRange {
     #[inline(always)]
     fn start_bound(&self) -> Bound<&usize> {
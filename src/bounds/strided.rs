@@ -0,0 +1,187 @@
+use core::iter::FusedIterator;
+
+use crate::bounds::SliceRange;
+
+/// An iterator over the indices of a [`SliceRange`], separated by a fixed step.
+///
+/// It yields `start, start + step, start + step * 2, ...` for as long as the index is
+/// less than `end`.
+///
+/// Unlike [`Iterator::step_by`], this can be constructed in `const`, and always knows
+/// its exact length.
+///
+/// See [`SliceRange::step_by_const`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Strided {
+    /// The next index to yield from the front.
+    start: usize,
+    /// The distance between consecutive indices.
+    step: usize,
+    /// The amount of indices that have yet to be yielded.
+    len: usize,
+}
+
+impl Strided {
+    /// Create a new [`Strided`] iterator over `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn new(
+        range: SliceRange,
+        step: usize,
+    ) -> Strided {
+        assert!(step != 0, "`step == 0`");
+
+        Strided {
+            start: range.start(),
+            step,
+            len: range.len().div_ceil(step),
+        }
+    }
+
+    /// Returns the distance between consecutive indices.
+    #[inline(always)]
+    #[must_use]
+    pub const fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns the amount of indices that have yet to be yielded.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the index `n` steps after the front.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `n < self.len`.
+    #[inline(always)]
+    #[must_use]
+    const unsafe fn nth_index_unchecked(
+        &self,
+        n: usize,
+    ) -> usize {
+        // SAFETY: The caller ensures that `n < self.len`, so the index lies within the range
+        //         that we were created from, and therefore cannot overflow.
+        unsafe { self.start.unchecked_add(n.unchecked_mul(self.step)) }
+    }
+}
+
+impl Iterator for Strided {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let index = self.start;
+
+        self.len -= 1;
+
+        // NOTE: The front may step past the end of the range once we're exhausted,
+        //       so we don't want to panic on overflow here.
+        self.start = self.start.wrapping_add(self.step);
+
+        Some(index)
+    }
+
+    #[inline]
+    fn nth(
+        &mut self,
+        n: usize,
+    ) -> Option<usize> {
+        if n >= self.len {
+            self.len = 0;
+
+            return None;
+        }
+
+        // SAFETY: We just checked that `n < self.len`.
+        self.start = unsafe { self.nth_index_unchecked(n) };
+        self.len -= n;
+
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<usize> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Strided {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: We know that `self.len` was previously greater than the new `self.len`.
+        Some(unsafe { self.nth_index_unchecked(self.len) })
+    }
+
+    #[inline]
+    fn nth_back(
+        &mut self,
+        n: usize,
+    ) -> Option<usize> {
+        if n >= self.len {
+            self.len = 0;
+
+            return None;
+        }
+
+        self.len -= n;
+
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for Strided {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl FusedIterator for Strided {}
+
+impl SliceRange {
+    /// Returns an iterator over the indices of this range, separated by `step`.
+    ///
+    /// It yields `start, start + step, start + step * 2, ...` for as long as the index
+    /// is less than `end`, and yields exactly `len.div_ceil(step)` indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn step_by_const(
+        self,
+        step: usize,
+    ) -> Strided {
+        Strided::new(self, step)
+    }
+}
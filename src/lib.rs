@@ -26,6 +26,9 @@ mod util;
 /// Marker types and traits that are used internally.
 mod marker;
 
+/// Fast byte searching that is used internally.
+mod memchr;
+
 /// Just some helpers for [`prim@str`]s.
 pub mod str;
 
@@ -37,3 +40,7 @@ pub mod bounds;
 
 /// Where the actual slide types reside.
 pub mod slide;
+
+/// Support for [`serde`](::serde).
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,281 @@
+/// The size of a machine word, in bytes.
+const WORD: usize = size_of::<usize>();
+
+/// A word with every byte set to `0x01`.
+const LO: usize = usize::from_ne_bytes([0x01; WORD]);
+
+/// A word with every byte set to `0x80`.
+const HI: usize = usize::from_ne_bytes([0x80; WORD]);
+
+/// Returns a word with every byte set to `byte`.
+#[inline(always)]
+#[must_use]
+const fn splat(byte: u8) -> usize {
+    usize::from_ne_bytes([byte; WORD])
+}
+
+/// Returns whether any byte within `word` is zero.
+#[inline(always)]
+#[must_use]
+const fn has_zero(word: usize) -> bool {
+    word.wrapping_sub(LO) & !word & HI != 0
+}
+
+/// Reads the word starting at `index` within `haystack`.
+///
+/// # Safety
+///
+/// The caller must ensure that `index + WORD <= haystack.len()`.
+#[inline(always)]
+#[must_use]
+const unsafe fn read_word(
+    haystack: &[u8],
+    index: usize,
+) -> usize {
+    // SAFETY: The caller ensures that there are at least `WORD` bytes after `index`.
+    unsafe {
+        haystack
+            .as_ptr()
+            .add(index)
+            .cast::<usize>()
+            .read_unaligned()
+    }
+}
+
+/// Returns the index of the first occurrence of `needle` within `haystack`, one byte
+/// at a time.
+#[inline]
+#[must_use]
+pub(crate) const fn memchr_fallback(
+    needle: u8,
+    haystack: &[u8],
+) -> Option<usize> {
+    let mut index = 0;
+
+    while index < haystack.len() {
+        if haystack[index] == needle {
+            return Some(index);
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Returns the index of the first occurrence of `needle` within `haystack`.
+///
+/// This scans a word at a time, only falling back to a byte at a time to locate the match
+/// within a word, or for the tail of `haystack`.
+#[inline]
+#[must_use]
+pub(crate) const fn memchr(
+    needle: u8,
+    haystack: &[u8],
+) -> Option<usize> {
+    if cfg!(feature = "opt_size") {
+        return memchr_fallback(needle, haystack);
+    }
+
+    let splat = splat(needle);
+    let mut index = 0;
+
+    while index + WORD <= haystack.len() {
+        // SAFETY: We just checked that there are at least `WORD` bytes after `index`.
+        let word = unsafe { read_word(haystack, index) };
+
+        if has_zero(word ^ splat) {
+            break;
+        }
+
+        index += WORD;
+    }
+
+    // NOTE: Either there's a match within the next word, or we're at the tail.
+    while index < haystack.len() {
+        if haystack[index] == needle {
+            return Some(index);
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Returns the index of the first byte within `haystack` that is any of `needles`, one
+/// byte at a time.
+#[inline]
+#[must_use]
+pub(crate) const fn memchr_any_fallback(
+    needles: &[u8],
+    haystack: &[u8],
+) -> Option<usize> {
+    let mut table = [false; 256];
+    let mut index = 0;
+
+    while index < needles.len() {
+        table[needles[index] as usize] = true;
+        index += 1;
+    }
+
+    index = 0;
+
+    while index < haystack.len() {
+        if table[haystack[index] as usize] {
+            return Some(index);
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Returns the index of the first byte within `haystack` that is any of `needles`.
+///
+/// Up to three needles are scanned for a word at a time, otherwise this uses a lookup
+/// table, one byte at a time.
+#[inline]
+#[must_use]
+pub(crate) const fn memchr_any(
+    needles: &[u8],
+    haystack: &[u8],
+) -> Option<usize> {
+    let (a, b, c) = match *needles {
+        [] => return None,
+        [a] => return memchr(a, haystack),
+        [a, b] => (a, b, b),
+        [a, b, c] => (a, b, c),
+        _ => return memchr_any_fallback(needles, haystack),
+    };
+
+    if cfg!(feature = "opt_size") {
+        return memchr_any_fallback(needles, haystack);
+    }
+
+    let (sa, sb, sc) = (splat(a), splat(b), splat(c));
+    let mut index = 0;
+
+    while index + WORD <= haystack.len() {
+        // SAFETY: We just checked that there are at least `WORD` bytes after `index`.
+        let word = unsafe { read_word(haystack, index) };
+
+        if has_zero(word ^ sa) || has_zero(word ^ sb) || has_zero(word ^ sc) {
+            break;
+        }
+
+        index += WORD;
+    }
+
+    // NOTE: Either there's a match within the next word, or we're at the tail.
+    while index < haystack.len() {
+        let byte = haystack[index];
+
+        if byte == a || byte == b || byte == c {
+            return Some(index);
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bytes used to fill haystacks, chosen to stress the word-at-a-time checks.
+    const FILL: [u8; 6] = [0x00, 0x01, 0x7f, 0x80, 0xfe, 0xff];
+
+    /// Returns haystack lengths around multiples of the word size.
+    fn lengths() -> impl Iterator<Item = usize> {
+        0..=4 * WORD + 3
+    }
+
+    #[test]
+    fn memchr_matches_fallback() {
+        let mut buf = [0u8; 4 * WORD + 3 + WORD];
+
+        for needle in [0x00, 0x01, 0x80, 0xff, b'a'] {
+            for fill in FILL.into_iter().filter(|&fill| fill != needle) {
+                for align in 0..WORD {
+                    for len in lengths() {
+                        buf.fill(fill);
+
+                        let haystack = &mut buf[align..align + len];
+
+                        assert_eq!(memchr(needle, haystack), None);
+                        assert_eq!(memchr_fallback(needle, haystack), None);
+
+                        for pos in 0..len {
+                            haystack.fill(fill);
+                            haystack[pos] = needle;
+
+                            // NOTE: A second match after the first must not be reported.
+                            if let Some(last) = haystack.last_mut() {
+                                *last = needle;
+                            }
+
+                            assert_eq!(memchr(needle, haystack), Some(pos), "{haystack:?}");
+                            assert_eq!(memchr_fallback(needle, haystack), Some(pos));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn memchr_any_matches_fallback() {
+        let needle_sets: [&[u8]; 6] = [
+            &[],
+            b"a",
+            &[b'a', 0x80],
+            &[b'a', 0x80, 0x00],
+            &[b'a', 0x80, 0x00, 0xff],
+            &[b'a', 0x80, 0x00, 0xff, b'z', 0x01],
+        ];
+        let mut buf = [0u8; 4 * WORD + 3 + WORD];
+
+        for needles in needle_sets {
+            for fill in FILL.into_iter().filter(|fill| !needles.contains(fill)) {
+                for align in 0..WORD {
+                    for len in lengths() {
+                        buf.fill(fill);
+
+                        let haystack = &mut buf[align..align + len];
+
+                        assert_eq!(memchr_any(needles, haystack), None);
+                        assert_eq!(memchr_any_fallback(needles, haystack), None);
+
+                        for &needle in needles {
+                            for pos in 0..len {
+                                haystack.fill(fill);
+                                haystack[pos] = needle;
+
+                                if let Some(last) = haystack.last_mut() {
+                                    *last = needles[0];
+                                }
+
+                                assert_eq!(
+                                    memchr_any(needles, haystack),
+                                    Some(pos),
+                                    "{needles:?} in {haystack:?}"
+                                );
+                                assert_eq!(memchr_any_fallback(needles, haystack), Some(pos));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn memchr_const() {
+        const { assert!(matches!(memchr(b'c', b"abcabc"), Some(2))) };
+        const { assert!(matches!(memchr_any(b"xc", b"abcabc"), Some(2))) };
+    }
+}
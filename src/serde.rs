@@ -0,0 +1,553 @@
+use core::fmt;
+
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+};
+
+use crate::{
+    bounds::SliceRange,
+    slice::Slice,
+    slide::{Slide, SlideMut},
+};
+
+/// Serializes the state of a slide as a `{ offset, len }` struct.
+#[inline]
+fn serialize_slide<Ser>(
+    serializer: Ser,
+    name: &'static str,
+    offset: usize,
+    len: usize,
+) -> Result<Ser::Ok, Ser::Error>
+where
+    Ser: Serializer,
+{
+    let mut state = serializer.serialize_struct(name, 2)?;
+
+    state.serialize_field("offset", &offset)?;
+    state.serialize_field("len", &len)?;
+    state.end()
+}
+
+impl<'a, S> Serialize for Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Serializes the offset of the cursor and the length of the source, but *not* the
+    /// source itself.
+    #[inline]
+    fn serialize<Ser>(
+        &self,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        serialize_slide(serializer, "Slide", self.offset(), self.source_len())
+    }
+}
+
+impl<'a, S> Serialize for SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Serializes the offset of the cursor and the length of the source, but *not* the
+    /// source itself.
+    #[inline]
+    fn serialize<Ser>(
+        &self,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        serialize_slide(serializer, "SlideMut", self.offset(), self.source_len())
+    }
+}
+
+/// The fields of a serialized slide.
+const SLIDE_FIELDS: &[&str] = &["offset", "len"];
+
+/// A field of a serialized slide.
+enum SlideField {
+    /// The `offset` field.
+    Offset,
+    /// The `len` field.
+    Len,
+}
+
+impl<'de> Deserialize<'de> for SlideField {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<SlideField, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// Visitor for [`SlideField`].
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = SlideField;
+
+            fn expecting(
+                &self,
+                f: &mut fmt::Formatter<'_>,
+            ) -> fmt::Result {
+                f.write_str("`offset` or `len`")
+            }
+
+            fn visit_u64<E>(
+                self,
+                value: u64,
+            ) -> Result<SlideField, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    0 => Ok(SlideField::Offset),
+                    1 => Ok(SlideField::Len),
+                    _ => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
+            }
+
+            fn visit_str<E>(
+                self,
+                value: &str,
+            ) -> Result<SlideField, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    "offset" => Ok(SlideField::Offset),
+                    "len" => Ok(SlideField::Len),
+                    _ => Err(E::unknown_field(value, SLIDE_FIELDS)),
+                }
+            }
+
+            fn visit_bytes<E>(
+                self,
+                value: &[u8],
+            ) -> Result<SlideField, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    b"offset" => Ok(SlideField::Offset),
+                    b"len" => Ok(SlideField::Len),
+                    _ => Err(E::invalid_value(de::Unexpected::Bytes(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Visitor for the `{ offset, len }` state of a slide.
+struct SlideVisitor;
+
+impl<'de> Visitor<'de> for SlideVisitor {
+    type Value = (usize, usize);
+
+    fn expecting(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("struct Slide")
+    }
+
+    fn visit_seq<A>(
+        self,
+        mut seq: A,
+    ) -> Result<(usize, usize), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let offset = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let len = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok((offset, len))
+    }
+
+    fn visit_map<A>(
+        self,
+        mut map: A,
+    ) -> Result<(usize, usize), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut offset = None;
+        let mut len = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                SlideField::Offset if offset.is_some() => {
+                    return Err(de::Error::duplicate_field("offset"));
+                }
+                SlideField::Offset => offset = Some(map.next_value()?),
+                SlideField::Len if len.is_some() => return Err(de::Error::duplicate_field("len")),
+                SlideField::Len => len = Some(map.next_value()?),
+            }
+        }
+
+        let offset = offset.ok_or_else(|| de::Error::missing_field("offset"))?;
+        let len = len.ok_or_else(|| de::Error::missing_field("len"))?;
+
+        Ok((offset, len))
+    }
+}
+
+/// Deserializes the `{ offset, len }` state of a slide, and returns the offset if `len`
+/// matches `source_len`.
+#[inline]
+fn deserialize_offset<'de, D>(
+    deserializer: D,
+    name: &'static str,
+    source_len: usize,
+) -> Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (offset, len) = deserializer.deserialize_struct(name, SLIDE_FIELDS, SlideVisitor)?;
+
+    if len != source_len {
+        return Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(len as u64),
+            &"the length of the source",
+        ));
+    }
+
+    Ok(offset)
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Deserialize the state written by [`Slide`]'s [`Serialize`] implementation,
+    /// restoring the cursor over `source`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serialized length differs from that of `source`, or if the
+    /// serialized offset is not a valid split boundary for `source`.
+    #[inline]
+    pub fn deserialize_with_source<'de, D>(
+        deserializer: D,
+        source: &'a S,
+    ) -> Result<Slide<'a, S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let offset = deserialize_offset(deserializer, "Slide", source.len())?;
+
+        Slide::try_with_offset(source, offset).map_err(de::Error::custom)
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Deserialize the state written by [`SlideMut`]'s [`Serialize`] implementation,
+    /// restoring the cursor over `source`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the serialized length differs from that of `source`, or if the
+    /// serialized offset is not a valid split boundary for `source`.
+    #[inline]
+    pub fn deserialize_with_source<'de, D>(
+        deserializer: D,
+        source: &'a mut S,
+    ) -> Result<SlideMut<'a, S>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let offset = deserialize_offset(deserializer, "SlideMut", source.len())?;
+
+        SlideMut::try_with_offset(source, offset).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for SliceRange {
+    #[inline]
+    fn serialize<Ser>(
+        &self,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        let mut state = serializer.serialize_struct("SliceRange", 2)?;
+
+        state.serialize_field("start", &self.start())?;
+        state.serialize_field("end", &self.end())?;
+        state.end()
+    }
+}
+
+/// The fields of a serialized [`SliceRange`].
+const RANGE_FIELDS: &[&str] = &["start", "end"];
+
+/// A field of a serialized [`SliceRange`].
+enum RangeField {
+    /// The `start` field.
+    Start,
+    /// The `end` field.
+    End,
+}
+
+impl<'de> Deserialize<'de> for RangeField {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<RangeField, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// Visitor for [`RangeField`].
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = RangeField;
+
+            fn expecting(
+                &self,
+                f: &mut fmt::Formatter<'_>,
+            ) -> fmt::Result {
+                f.write_str("`start` or `end`")
+            }
+
+            fn visit_u64<E>(
+                self,
+                value: u64,
+            ) -> Result<RangeField, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    0 => Ok(RangeField::Start),
+                    1 => Ok(RangeField::End),
+                    _ => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+                }
+            }
+
+            fn visit_str<E>(
+                self,
+                value: &str,
+            ) -> Result<RangeField, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    "start" => Ok(RangeField::Start),
+                    "end" => Ok(RangeField::End),
+                    _ => Err(E::unknown_field(value, RANGE_FIELDS)),
+                }
+            }
+
+            fn visit_bytes<E>(
+                self,
+                value: &[u8],
+            ) -> Result<RangeField, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    b"start" => Ok(RangeField::Start),
+                    b"end" => Ok(RangeField::End),
+                    _ => Err(E::invalid_value(de::Unexpected::Bytes(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Visitor for [`SliceRange`].
+struct RangeVisitor;
+
+impl<'de> Visitor<'de> for RangeVisitor {
+    type Value = SliceRange;
+
+    fn expecting(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("struct SliceRange")
+    }
+
+    fn visit_seq<A>(
+        self,
+        mut seq: A,
+    ) -> Result<SliceRange, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let start = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let end = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        SliceRange::try_new(start, end).map_err(de::Error::custom)
+    }
+
+    fn visit_map<A>(
+        self,
+        mut map: A,
+    ) -> Result<SliceRange, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut start = None;
+        let mut end = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                RangeField::Start if start.is_some() => {
+                    return Err(de::Error::duplicate_field("start"));
+                }
+                RangeField::Start => start = Some(map.next_value()?),
+                RangeField::End if end.is_some() => return Err(de::Error::duplicate_field("end")),
+                RangeField::End => end = Some(map.next_value()?),
+            }
+        }
+
+        let start = start.ok_or_else(|| de::Error::missing_field("start"))?;
+        let end = end.ok_or_else(|| de::Error::missing_field("end"))?;
+
+        SliceRange::try_new(start, end).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for SliceRange {
+    /// Deserializes a `{ start, end }` struct, failing if `start > end`.
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<SliceRange, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("SliceRange", RANGE_FIELDS, RangeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_range_map_round_trip() {
+        let range = SliceRange::new(3, 7);
+        let json = serde_json::to_string(&range).unwrap();
+
+        assert_eq!(json, r#"{"start":3,"end":7}"#);
+        assert_eq!(serde_json::from_str::<SliceRange>(&json).unwrap(), range);
+        assert_eq!(
+            serde_json::from_str::<SliceRange>(r#"{"end":7,"start":3}"#).unwrap(),
+            range
+        );
+    }
+
+    #[test]
+    fn slice_range_seq_round_trip() {
+        let range = SliceRange::new(0, 0);
+        let value = serde_json::to_value(range).unwrap();
+        let seq = serde_json::json!([value["start"], value["end"]]);
+
+        assert_eq!(serde_json::from_value::<SliceRange>(seq).unwrap(), range);
+        assert_eq!(
+            serde_json::from_str::<SliceRange>("[2, 5]").unwrap(),
+            SliceRange::new(2, 5)
+        );
+    }
+
+    #[test]
+    fn slice_range_rejects_reversed() {
+        assert!(serde_json::from_str::<SliceRange>(r#"{"start":7,"end":3}"#).is_err());
+        assert!(serde_json::from_str::<SliceRange>("[7, 3]").is_err());
+    }
+
+    #[test]
+    fn slice_range_rejects_duplicate_fields() {
+        let error = serde_json::from_str::<SliceRange>(r#"{"start":1,"start":2,"end":3}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("duplicate field `start`"), "{error}");
+
+        let error = serde_json::from_str::<SliceRange>(r#"{"start":1,"end":2,"end":3}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("duplicate field `end`"), "{error}");
+    }
+
+    #[test]
+    fn slice_range_rejects_missing_fields() {
+        let error = serde_json::from_str::<SliceRange>(r#"{"end":3}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("missing field `start`"), "{error}");
+
+        let error = serde_json::from_str::<SliceRange>(r#"{"start":1}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("missing field `end`"), "{error}");
+
+        assert!(serde_json::from_str::<SliceRange>("[1]").is_err());
+    }
+
+    #[test]
+    fn slide_round_trip() {
+        let source = b"hello world";
+        let mut slide = Slide::new(&source[..]);
+
+        slide.advance(6);
+
+        let json = serde_json::to_string(&slide).unwrap();
+        assert_eq!(json, r#"{"offset":6,"len":11}"#);
+
+        let mut de = serde_json::Deserializer::from_str(&json);
+        let restored = Slide::deserialize_with_source(&mut de, &source[..]).unwrap();
+        assert_eq!(restored.offset(), 6);
+        assert_eq!(restored.remaining(), b"world");
+    }
+
+    #[test]
+    fn slide_mut_round_trip() {
+        let mut source = *b"abcd";
+        let mut slide = SlideMut::new(&mut source[..]);
+
+        slide.advance(1);
+
+        let json = serde_json::to_string(&slide).unwrap();
+        assert_eq!(json, r#"{"offset":1,"len":4}"#);
+
+        let mut de = serde_json::Deserializer::from_str("[3, 4]");
+        let restored = SlideMut::deserialize_with_source(&mut de, &mut source[..]).unwrap();
+        assert_eq!(restored.remaining(), b"d");
+    }
+
+    #[test]
+    fn slide_rejects_mismatched_source() {
+        let source = "héllo";
+
+        let mut de = serde_json::Deserializer::from_str(r#"{"offset":1,"len":5}"#);
+        assert!(Slide::deserialize_with_source(&mut de, source).is_err());
+
+        // NOTE: `2` is inside of the two byte `é`.
+        let mut de = serde_json::Deserializer::from_str(r#"{"offset":2,"len":6}"#);
+        assert!(Slide::deserialize_with_source(&mut de, source).is_err());
+
+        let mut de = serde_json::Deserializer::from_str(r#"{"offset":7,"len":6}"#);
+        assert!(Slide::deserialize_with_source(&mut de, source).is_err());
+
+        let mut de = serde_json::Deserializer::from_str(r#"{"offset":3,"len":6}"#);
+        let slide = Slide::deserialize_with_source(&mut de, source).unwrap();
+        assert_eq!(slide.remaining(), "llo");
+    }
+}
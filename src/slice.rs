@@ -3,7 +3,8 @@
 #![allow(type_alias_bounds, clippy::empty_docs)]
 
 use crate::{
-    macros::unreachable_unchecked, marker::TypeEq, mem::NoDrop, slice::private::SliceKind,
+    bounds::SliceRange, macros::unreachable_unchecked, marker::TypeEq, mem::NoDrop,
+    slice::private::SliceKind,
 };
 use core::{convert::Infallible, fmt, num::NonZero, ptr::NonNull, str::Utf8Error};
 
@@ -679,6 +680,201 @@ methods! {
     ) {
         S::KIND.0.split_at_mut(slice, index)
     }
+
+    /// Given some slice, split it at `index`, returning [`None`] upon failure.
+    ///
+    /// This is a convenience wrapper around [`Slice::try_split_at`] as implemented for
+    /// `S`/`Self` that discards the error.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((head, tail))` upon success.
+    ///
+    /// - `None` if `index` is out of bounds (`index > len`), or if some other error defined
+    ///   by `S`/`Self` occurs. See the documentation for [`Slice::validate_split_at`] as
+    ///   implemented for `S`/`Self` for more info.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_at_checked['a, S](
+        slice: &'a S,
+        index: usize,
+    ) -> Option<Split<'a, S>>
+    where (
+        S: Slice + ?Sized,
+    ) {
+        match NoDrop::new(try_split_at(slice, index)).transpose() {
+            Ok(split) => Some(split.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Given some mutable slice, split it at `index`, returning [`None`] upon failure.
+    ///
+    /// This is a convenience wrapper around [`Slice::try_split_at_mut`] as implemented for
+    /// `S`/`Self` that discards the error.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((head, tail))` upon success.
+    ///
+    /// - `None` if `index` is out of bounds (`index > len`), or if some other error defined
+    ///   by `S`/`Self` occurs. See the documentation for [`Slice::validate_split_at`] as
+    ///   implemented for `S`/`Self` for more info.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_at_mut_checked['a, S](
+        slice: &'a mut S,
+        index: usize,
+    ) -> Option<SplitMut<'a, S>>
+    where (
+        S: Slice + ?Sized,
+    ) {
+        match NoDrop::new(try_split_at_mut(slice, index)).transpose() {
+            Ok(split) => Some(split.into_inner()),
+            Err(..) => None,
+        }
+    }
+}
+
+/// Returns a reference to the first element of the provided slice, or [`None`] if it is empty.
+///
+/// For [`prim@str`]s, this is the first byte.
+#[inline(always)]
+#[must_use]
+#[track_caller]
+pub const fn first<'a, S>(slice: &'a S) -> Option<&'a S::Elem>
+where
+    S: Slice + ?Sized,
+{
+    as_elems(slice).first()
+}
+
+/// Returns a reference to the last element of the provided slice, or [`None`] if it is empty.
+///
+/// For [`prim@str`]s, this is the last byte.
+#[inline(always)]
+#[must_use]
+#[track_caller]
+pub const fn last<'a, S>(slice: &'a S) -> Option<&'a S::Elem>
+where
+    S: Slice + ?Sized,
+{
+    as_elems(slice).last()
+}
+
+/// Returns a reference to the element at `index` in the provided slice, or [`None`] if
+/// `index` is out of bounds (`index >= len`).
+///
+/// For [`prim@str`]s, this is the byte at `index`, regardless of whether it lies on a
+/// UTF-8 character boundary.
+#[inline(always)]
+#[must_use]
+#[track_caller]
+pub const fn get_elem<'a, S>(
+    slice: &'a S,
+    index: usize,
+) -> Option<&'a S::Elem>
+where
+    S: Slice + ?Sized,
+{
+    let elems = as_elems(slice);
+
+    if index < elems.len() {
+        Some(&elems[index])
+    } else {
+        None
+    }
+}
+
+/// Returns the subslice of the provided slice at `range`, or [`None`] upon failure.
+///
+/// # Returns
+///
+/// - `Some(subslice)` upon success.
+///
+/// - `None` if `range` is out of bounds (`range.end() > len`), or if either endpoint
+///   is not a valid split boundary. See the documentation for [`Slice::validate_split_at`]
+///   as implemented for `S` for more info.
+#[inline]
+#[must_use]
+#[track_caller]
+pub const fn get<S>(
+    slice: &S,
+    range: SliceRange,
+) -> Option<&S>
+where
+    S: Slice + ?Sized,
+{
+    let head = match split_at_checked(slice, range.end()) {
+        Some((head, _)) => head,
+        None => return None,
+    };
+
+    match split_at_checked(head, range.start()) {
+        Some((_, subslice)) => Some(subslice),
+        None => None,
+    }
+}
+
+/// Returns the subslice of the provided mutable slice at `range`, or [`None`] upon failure.
+///
+/// # Returns
+///
+/// - `Some(subslice)` upon success.
+///
+/// - `None` if `range` is out of bounds (`range.end() > len`), or if either endpoint
+///   is not a valid split boundary. See the documentation for [`Slice::validate_split_at`]
+///   as implemented for `S` for more info.
+#[inline]
+#[must_use]
+#[track_caller]
+pub const fn get_mut<S>(
+    slice: &mut S,
+    range: SliceRange,
+) -> Option<&mut S>
+where
+    S: Slice + ?Sized,
+{
+    let head = match split_at_mut_checked(slice, range.end()) {
+        Some((head, _)) => head,
+        None => return None,
+    };
+
+    match split_at_mut_checked(head, range.start()) {
+        Some((_, subslice)) => Some(subslice),
+        None => None,
+    }
+}
+
+/// Returns the sum of the lengths of the provided slices, in elements.
+///
+/// This is useful for pre-sizing a buffer that the slices will be concatenated into.
+///
+/// # Panics
+///
+/// Panics if the sum overflows a [`usize`].
+#[inline]
+#[must_use]
+#[track_caller]
+pub const fn total_len<S>(slices: &[&S]) -> usize
+where
+    S: Slice + ?Sized,
+{
+    let mut total = 0usize;
+    let mut index = 0;
+
+    while index < slices.len() {
+        total = match total.checked_add(len(slices[index])) {
+            Some(total) => total,
+            None => panic!("total length overflowed a `usize`"),
+        };
+
+        index += 1;
+    }
+
+    total
 }
 
 /// Trait for the various slice types we support.
@@ -933,6 +1129,56 @@ pub unsafe trait Slice: private::Sealed {
         &'a mut self,
         index: usize,
     ) -> SplitMut<'a, Self>;
+
+    #[doc = docs!(split_at_checked)]
+    #[must_use]
+    #[track_caller]
+    fn split_at_checked<'a>(
+        &'a self,
+        index: usize,
+    ) -> Option<Split<'a, Self>>;
+
+    #[doc = docs!(split_at_mut_checked)]
+    #[must_use]
+    #[track_caller]
+    fn split_at_mut_checked<'a>(
+        &'a mut self,
+        index: usize,
+    ) -> Option<SplitMut<'a, Self>>;
+
+    /// Returns whether `needle` is a prefix of this slice.
+    ///
+    /// For [`prim@str`]s, this compares bytes. Since both operands are well-formed UTF-8,
+    /// a matching prefix always ends on a character boundary.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    fn starts_with(
+        &self,
+        needle: &Self,
+    ) -> bool
+    where
+        Self::Elem: PartialEq,
+    {
+        as_elems(self).starts_with(as_elems(needle))
+    }
+
+    /// Returns whether `needle` is a suffix of this slice.
+    ///
+    /// For [`prim@str`]s, this compares bytes. Since both operands are well-formed UTF-8,
+    /// a matching suffix always starts on a character boundary.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    fn ends_with(
+        &self,
+        needle: &Self,
+    ) -> bool
+    where
+        Self::Elem: PartialEq,
+    {
+        as_elems(self).ends_with(as_elems(needle))
+    }
 }
 
 /// Gets a type or it's alternative, preferring the alternative.
@@ -1258,6 +1504,26 @@ macro_rules! slice {
                     ) -> SplitMut<'a, $slice> {
                         split_at_mut(self, index)
                     }
+
+                    #[doc = docs!(split_at_checked)]
+                    #[inline(always)]
+                    #[track_caller]
+                    fn split_at_checked<'a>(
+                        &'a self,
+                        index: usize,
+                    ) -> Option<Split<'a, $slice>> {
+                        split_at_checked(self, index)
+                    }
+
+                    #[doc = docs!(split_at_mut_checked)]
+                    #[inline(always)]
+                    #[track_caller]
+                    fn split_at_mut_checked<'a>(
+                        &'a mut self,
+                        index: usize,
+                    ) -> Option<SplitMut<'a, $slice>> {
+                        split_at_mut_checked(self, index)
+                    }
                 }
 
                 impl $(< $($gen)* >)? From<$from_elems> for FromElemsError<$slice> {
@@ -1,6 +1,6 @@
-use core::{cmp::Ordering, error::Error, fmt, hash, mem, num::NonZero};
+use core::{cmp::Ordering, error::Error, fmt, hash, mem, num::NonZero, ptr};
 
-use crate::{macros::unreachable_unchecked, slice::Slice};
+use crate::{macros::unreachable_unchecked, mem::NoDrop, slice::Slice};
 
 /// The error type that is returned when creating
 /// a slice from its component elements.
@@ -377,6 +377,63 @@ type _OobIndex = i128;
 /// This is platform dependent.
 pub type OobIndex = _OobIndex;
 
+/// Add two out-of-bounds indices together.
+///
+/// As [`OobIndex`] is a type alias of a primitive, this is provided as a free function.
+///
+/// # Returns
+///
+/// - `Some(sum)` upon success.
+///
+/// - `None` if the sum overflows, or if it is zero.
+#[inline]
+#[must_use]
+pub const fn oob_index_checked_add(
+    lhs: NonZero<OobIndex>,
+    rhs: NonZero<OobIndex>,
+) -> Option<NonZero<OobIndex>> {
+    match lhs.get().checked_add(rhs.get()) {
+        Some(sum) => NonZero::new(sum),
+        None => None,
+    }
+}
+
+/// Subtract an out-of-bounds index from another.
+///
+/// As [`OobIndex`] is a type alias of a primitive, this is provided as a free function.
+///
+/// # Returns
+///
+/// - `Some(difference)` upon success.
+///
+/// - `None` if the difference overflows, or if it is zero.
+#[inline]
+#[must_use]
+pub const fn oob_index_checked_sub(
+    lhs: NonZero<OobIndex>,
+    rhs: NonZero<OobIndex>,
+) -> Option<NonZero<OobIndex>> {
+    match lhs.get().checked_sub(rhs.get()) {
+        Some(difference) => NonZero::new(difference),
+        None => None,
+    }
+}
+
+/// Returns the magnitude of an out-of-bounds index.
+///
+/// As [`OobIndex`] is wider than [`usize`], the magnitude is clamped to [`usize::MAX`].
+#[inline]
+#[must_use]
+pub const fn oob_index_unsigned_abs(index: NonZero<OobIndex>) -> usize {
+    let abs = index.get().unsigned_abs();
+
+    if abs > usize::MAX as _ {
+        usize::MAX
+    } else {
+        abs as usize
+    }
+}
+
 /// An error detailing why it is not possible to split some slice.
 pub enum SplitError<S>
 where
@@ -398,6 +455,17 @@ where
     S: Slice + ?Sized,
 {
     /// Returns the index that caused this error.
+    ///
+    /// This is the requested split index, not the amount by which it overflowed.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(index)` for [`SplitError::OutOfBounds`] if the index lies after the end.
+    ///
+    /// - `Some(index)` for [`SplitError::Other`], as reported by `S`.
+    ///
+    /// - `None` for [`SplitError::OutOfBounds`] if the index lies before the start, as it
+    ///   is negative and cannot be represented as a [`usize`].
     #[inline(always)]
     #[track_caller]
     #[must_use]
@@ -413,6 +481,27 @@ where
         }
     }
 
+    /// Convert this into the error specific to `S`.
+    ///
+    /// This is a `const` equivalent to the [`TryFrom`] implementation for `S::SplitErr`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(error)` if this is [`SplitError::Other`].
+    ///
+    /// - `Err(FromSplitErrorFailure::NotOther)` if this is [`SplitError::OutOfBounds`].
+    #[inline]
+    #[track_caller]
+    pub const fn into_other(self) -> Result<S::SplitErr, FromSplitErrorFailure> {
+        let error = NoDrop::new(self);
+
+        match error.as_ref() {
+            // SAFETY: `error` is never dropped, so this is the only copy of `other`.
+            SplitError::Other(other) => Ok(unsafe { ptr::read(other) }),
+            SplitError::OutOfBounds { .. } => Err(FromSplitErrorFailure::NotOther),
+        }
+    }
+
     /// Panics with an error message corresponding to this error.
     #[inline(never)]
     #[track_caller]
@@ -692,3 +781,125 @@ impl core::error::Error for FromSplitErrorFailure {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{slice::str::StrSplitError, slide::Slide};
+
+    /// Shorthand for creating a non-zero [`OobIndex`].
+    #[track_caller]
+    fn nz(index: OobIndex) -> NonZero<OobIndex> {
+        NonZero::new(index).unwrap()
+    }
+
+    #[test]
+    fn oob_index_checked_add_extremes() {
+        assert_eq!(oob_index_checked_add(nz(2), nz(3)), Some(nz(5)));
+        assert_eq!(oob_index_checked_add(nz(-2), nz(-3)), Some(nz(-5)));
+        assert_eq!(oob_index_checked_add(nz(5), nz(-5)), None);
+        assert_eq!(oob_index_checked_add(nz(OobIndex::MAX), nz(1)), None);
+        assert_eq!(oob_index_checked_add(nz(OobIndex::MIN), nz(-1)), None);
+        assert_eq!(
+            oob_index_checked_add(nz(OobIndex::MIN), nz(OobIndex::MAX)),
+            Some(nz(-1))
+        );
+        assert_eq!(
+            oob_index_checked_add(nz(OobIndex::MAX), nz(-1)),
+            Some(nz(OobIndex::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn oob_index_checked_sub_extremes() {
+        assert_eq!(oob_index_checked_sub(nz(5), nz(3)), Some(nz(2)));
+        assert_eq!(oob_index_checked_sub(nz(3), nz(5)), Some(nz(-2)));
+        assert_eq!(oob_index_checked_sub(nz(5), nz(5)), None);
+        assert_eq!(
+            oob_index_checked_sub(nz(OobIndex::MAX), nz(OobIndex::MAX)),
+            None
+        );
+        assert_eq!(oob_index_checked_sub(nz(OobIndex::MIN), nz(1)), None);
+        assert_eq!(oob_index_checked_sub(nz(OobIndex::MAX), nz(-1)), None);
+        assert_eq!(
+            oob_index_checked_sub(nz(-1), nz(OobIndex::MAX)),
+            Some(nz(OobIndex::MIN))
+        );
+    }
+
+    #[test]
+    fn oob_index_unsigned_abs_clamps() {
+        assert_eq!(oob_index_unsigned_abs(nz(5)), 5);
+        assert_eq!(oob_index_unsigned_abs(nz(-5)), 5);
+        assert_eq!(
+            oob_index_unsigned_abs(nz(usize::MAX as OobIndex)),
+            usize::MAX
+        );
+        assert_eq!(
+            oob_index_unsigned_abs(nz(-(usize::MAX as OobIndex))),
+            usize::MAX
+        );
+        assert_eq!(
+            oob_index_unsigned_abs(nz(usize::MAX as OobIndex + 1)),
+            usize::MAX
+        );
+        assert_eq!(oob_index_unsigned_abs(nz(OobIndex::MAX)), usize::MAX);
+        assert_eq!(oob_index_unsigned_abs(nz(OobIndex::MIN)), usize::MAX);
+    }
+
+    #[test]
+    fn oob_index_const() {
+        const SUM: Option<NonZero<OobIndex>> =
+            oob_index_checked_add(NonZero::new(2).unwrap(), NonZero::new(-2).unwrap());
+        const ABS: usize = oob_index_unsigned_abs(NonZero::new(OobIndex::MIN).unwrap());
+
+        assert_eq!(SUM, None);
+        assert_eq!(ABS, usize::MAX);
+    }
+
+    #[test]
+    fn split_error_index_after_end() {
+        let error = SplitError::<[u8]>::OutOfBounds {
+            index: nz(7),
+            len: 5,
+        };
+
+        assert_eq!(error.index(), Some(7));
+
+        let error = Slide::new(&[1u8, 2][..]).try_peek(5).unwrap_err();
+
+        assert_eq!(error.index(), Some(5));
+    }
+
+    #[test]
+    fn split_error_index_before_start() {
+        let error = SplitError::<[u8]>::OutOfBounds {
+            index: nz(-3),
+            len: 5,
+        };
+
+        assert_eq!(error.index(), None);
+    }
+
+    #[test]
+    fn split_error_index_beyond_usize() {
+        let error = SplitError::<[u8]>::OutOfBounds {
+            index: nz(usize::MAX as OobIndex + 1),
+            len: 5,
+        };
+
+        assert_eq!(error.index(), None);
+    }
+
+    #[test]
+    fn split_error_index_other_str() {
+        let error = SplitError::<str>::Other(StrSplitError::InvalidCharBoundary { index: 1 });
+
+        assert_eq!(error.index(), Some(1));
+
+        let error = Slide::new("a\u{e9}").try_advance(2).unwrap_err();
+
+        assert!(matches!(error, SplitError::Other(_)));
+        assert_eq!(error.index(), Some(2));
+    }
+}
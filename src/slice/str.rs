@@ -1,5 +1,4 @@
 use core::{
-    cmp::Ordering,
     fmt, mem,
     num::NonZero,
     ptr::{self, NonNull},
@@ -12,8 +11,7 @@ use crate::{
     slice::{
         AsElemsError, FromElemsError, OobIndex, Split, SplitError, SplitMut, split_error_handler,
     },
-    str::is_utf8_char_boundary,
-    util::cmp_usize,
+    str::is_char_boundary,
 };
 
 methods! {
@@ -339,34 +337,23 @@ methods! {
         slice: &'a str,
         index: usize,
     ) -> Result<(), SplitError<str>> {
-        if index == 0 {
-            // NOTE: It is always sound to get `&slice[0..]` and `&slice[..0]`.
+        if index > slice.len() {
+            // NOTE: If `index > slice.len()`, then it is out of bounds.
+            Err(SplitError::OutOfBounds {
+                index: NonZero::new(index as OobIndex).unwrap(),
+                len: slice.len(),
+            })
+        } else if is_char_boundary(slice, index) {
+            // NOTE: If `index` is in bounds and lies on a character boundary, then it is always
+            //       sound to get `&slice[..index]` and `&slice[index..]`.
             Ok(())
         } else {
-            match cmp_usize(index, slice.len()) {
-                // NOTE: It is always sound to get `&slice[..slice.len()]` and `&slice[slice.len()..]`.
-                Ordering::Equal => Ok(()),
-
-                // NOTE: If `index > slice.len()`, then it is out of bounds.
-                Ordering::Greater => Err(SplitError::OutOfBounds {
-                    index: NonZero::new(index as OobIndex).unwrap(),
-                    len: slice.len(),
-                }),
-
-                // NOTE: If `index < slice.len()` and the byte at `index` is a valid UTF-8 character boundary,
-                //       then it is always sound to get `&slice[..index]` and `&slice[index..]`.
-                Ordering::Less if is_utf8_char_boundary(slice.as_bytes()[index]) => Ok(()),
-
-                // NOTE: If `index < slice.len()` and the byte at `index` is not a valid UTF-8 character boundary,
-                //       then it is *not* sound to get `&slice[..index]` and `&slice[index..]`.
-                Ordering::Less => Err(SplitError::Other(StrSplitError::InvalidCharBoundary {
-                    index,
-                })),
-            }
+            // NOTE: If `index` is in bounds but does not lie on a character boundary, then it is
+            //       *not* sound to get `&slice[..index]` and `&slice[index..]`.
+            Err(SplitError::Other(StrSplitError::InvalidCharBoundary { index }))
         }
     }
 
-
     /// Split a string at the given index without any checks.
     ///
     /// # Safety
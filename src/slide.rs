@@ -6,3 +6,94 @@ pub(crate) mod location;
 
 /// Raw slides.
 pub(crate) mod raw;
+
+/// Implementation details.
+pub(crate) mod private;
+
+/// Module for the [`Slide`] type.
+mod slide_ref;
+
+/// Module for the [`SlideMut`] type.
+mod slide_mut;
+
+/// Module for the [`SlideStr`] type.
+mod slide_str;
+
+/// Module for the [`StackSlide`] type.
+mod stack;
+
+/// Module for splitting slides on delimiters.
+mod split;
+
+/// Module for iterating over the elements of slides.
+mod iter;
+
+/// Module for asserting that slides are exhausted.
+mod eof;
+
+/// Module for checking the invariants of slides.
+mod invariant;
+
+/// Module for the [`Region`] type.
+mod region;
+
+/// Module for the [`Direction`] type.
+mod direction;
+
+/// Module for fast byte searching within slides.
+mod search;
+
+/// Module for NUL-terminated strings within byte slides.
+mod cstr;
+
+/// Module for the [`Checkpoint`] type.
+mod checkpoint;
+
+/// Module for the [`Reversed`] and [`ReversedMut`] views.
+mod reversed;
+
+/// Module for iterating over slides in fixed-size chunks.
+mod chunks;
+
+/// Module for [`std::io`] support.
+#[cfg(feature = "std")]
+mod io;
+
+#[doc(inline)]
+pub use slide_ref::Slide;
+
+#[doc(inline)]
+pub use slide_mut::{SlideMut, concat_into};
+
+#[doc(inline)]
+pub use slide_str::SlideStr;
+
+#[doc(inline)]
+pub use stack::{CapacityError, StackSlide};
+
+#[doc(inline)]
+pub use split::{Delimiter, SplitInclusive, SplitOn, Splits, SplitsMut};
+
+#[doc(inline)]
+pub use iter::{Indexed, IterBackward, IterForward, IterRemaining};
+
+#[doc(inline)]
+pub use eof::TrailingData;
+
+#[doc(inline)]
+pub use invariant::SlideInvariantError;
+
+#[doc(inline)]
+pub use region::Region;
+
+#[doc(inline)]
+pub use direction::Direction;
+
+#[doc(inline)]
+pub use checkpoint::Checkpoint;
+
+#[doc(inline)]
+pub use reversed::{Reversed, ReversedMut};
+
+#[doc(inline)]
+pub use chunks::{Chunks, ChunksExact, ChunksExactMut, ChunksMut};
@@ -0,0 +1,294 @@
+use core::{fmt, hash, marker::PhantomData};
+
+use crate::{
+    slice::Slice,
+    slide::{Slide, SlideMut},
+};
+
+/// A snapshot of the cursor of a slide over some `S`, which can be restored later.
+///
+/// This is useful for backtracking: take a checkpoint, attempt to parse something, and
+/// restore the checkpoint upon failure.
+///
+/// See [`Slide::checkpoint`] and [`SlideMut::checkpoint`].
+pub struct Checkpoint<S>
+where
+    S: Slice + ?Sized,
+{
+    /// The offset of the cursor when this checkpoint was taken.
+    offset: usize,
+    /// Ties this checkpoint to slides over `S`.
+    _marker: PhantomData<fn() -> *const S>,
+}
+
+impl<S> Checkpoint<S>
+where
+    S: Slice + ?Sized,
+{
+    /// Create a new [`Checkpoint`] at `offset`.
+    #[inline(always)]
+    #[must_use]
+    const fn new(offset: usize) -> Checkpoint<S> {
+        Checkpoint {
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the offset of the cursor when this checkpoint was taken.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<S> Clone for Checkpoint<S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Checkpoint<S> where S: Slice + ?Sized {}
+
+impl<S> PartialEq for Checkpoint<S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<S> Eq for Checkpoint<S> where S: Slice + ?Sized {}
+
+impl<S> hash::Hash for Checkpoint<S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline]
+    fn hash<H: hash::Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.offset.hash(state);
+    }
+}
+
+impl<S> fmt::Debug for Checkpoint<S>
+where
+    S: Slice + ?Sized,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("Checkpoint")
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// Panics because a checkpoint at `offset` could not be restored onto a source of length `len`.
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn restore_failed(
+    offset: usize,
+    len: usize,
+) -> ! {
+    if offset > len {
+        panic!("cannot restore checkpoint at offset {offset} as the source has length {len}")
+    } else {
+        panic!("cannot restore checkpoint at offset {offset} as it is not a valid split boundary")
+    }
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Take a [`Checkpoint`] of the current cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checkpoint(&self) -> Checkpoint<S> {
+        Checkpoint::new(self.offset())
+    }
+
+    /// Move the cursor back to where it was when `checkpoint` was taken.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the checkpoint was restored. Upon failure, the cursor is left
+    /// untouched.
+    ///
+    /// This fails if the offset of `checkpoint` is out of bounds or not a valid split
+    /// boundary for this slide. That can happen if `checkpoint` was taken from a different
+    /// slide, or if the end of this slide has since been moved before it.
+    #[inline]
+    pub const fn restore_checked(
+        &mut self,
+        checkpoint: Checkpoint<S>,
+    ) -> bool {
+        if checkpoint.offset >= self.offset() {
+            self.advance_to_checked(checkpoint.offset).is_some()
+        } else {
+            self.rewind_to_checked(checkpoint.offset).is_some()
+        }
+    }
+
+    /// Move the cursor back to where it was when `checkpoint` was taken.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset of `checkpoint` is out of bounds or not a valid split boundary
+    /// for this slide. That can happen if `checkpoint` was taken from a different slide,
+    /// or if the end of this slide has since been moved before it.
+    #[inline]
+    #[track_caller]
+    pub fn restore(
+        &mut self,
+        checkpoint: Checkpoint<S>,
+    ) {
+        if !self.restore_checked(checkpoint) {
+            restore_failed(checkpoint.offset, self.source_len())
+        }
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Take a [`Checkpoint`] of the current cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checkpoint(&self) -> Checkpoint<S> {
+        Checkpoint::new(self.offset())
+    }
+
+    /// Move the cursor back to where it was when `checkpoint` was taken.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the checkpoint was restored. Upon failure, the cursor is left
+    /// untouched.
+    ///
+    /// This fails if the offset of `checkpoint` is out of bounds or not a valid split
+    /// boundary for this slide. That can happen if `checkpoint` was taken from a different
+    /// slide, or if the end of this slide has since been moved before it, such as by
+    /// [`SlideMut::retain_remaining`] or [`SlideMut::dedup_remaining`] after a rewind.
+    #[inline]
+    pub const fn restore_checked(
+        &mut self,
+        checkpoint: Checkpoint<S>,
+    ) -> bool {
+        if checkpoint.offset >= self.offset() {
+            self.advance_to_checked(checkpoint.offset).is_some()
+        } else {
+            self.rewind_to_checked(checkpoint.offset).is_some()
+        }
+    }
+
+    /// Move the cursor back to where it was when `checkpoint` was taken.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset of `checkpoint` is out of bounds or not a valid split boundary
+    /// for this slide. That can happen if `checkpoint` was taken from a different slide,
+    /// or if the end of this slide has since been moved before it, such as by
+    /// [`SlideMut::retain_remaining`] or [`SlideMut::dedup_remaining`] after a rewind.
+    #[inline]
+    #[track_caller]
+    pub fn restore(
+        &mut self,
+        checkpoint: Checkpoint<S>,
+    ) {
+        if !self.restore_checked(checkpoint) {
+            restore_failed(checkpoint.offset, self.source_len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restore_forward_and_back() {
+        let mut slide = Slide::new(&b"hello world"[..]);
+
+        slide.advance(2);
+        let checkpoint = slide.checkpoint();
+        assert_eq!(checkpoint.offset(), 2);
+
+        slide.advance(5);
+        slide.restore(checkpoint);
+        assert_eq!(slide.offset(), 2);
+
+        slide.rewind(2);
+        assert!(slide.restore_checked(checkpoint));
+        assert_eq!(slide.offset(), 2);
+    }
+
+    #[test]
+    fn restore_checked_rejects_foreign_checkpoint() {
+        let long = Slide::with_offset(&b"hello world"[..], 8);
+        let mut short = Slide::with_offset(&b"hey"[..], 1);
+
+        assert!(!short.restore_checked(long.checkpoint()));
+        assert_eq!(short.offset(), 1);
+
+        // NOTE: `1` lies inside of the two byte `é`.
+        let mut text = Slide::new("é");
+
+        assert!(!text.restore_checked(Checkpoint::new(1)));
+        assert_eq!(text.offset(), 0);
+    }
+
+    #[test]
+    fn restore_checked_after_shrink() {
+        let mut buf = [1u8, 2, 3, 4, 5, 6];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(5);
+        let checkpoint = slide.checkpoint();
+
+        slide.rewind(4);
+        assert_eq!(slide.retain_remaining(|&x| x % 2 == 0), 3);
+        assert_eq!(slide.source_len(), 4);
+
+        assert!(!slide.restore_checked(checkpoint));
+        assert_eq!(slide.offset(), 1);
+    }
+
+    #[test]
+    #[should_panic = "cannot restore checkpoint at offset 5 as the source has length 4"]
+    fn restore_after_shrink() {
+        let mut buf = [1u8, 2, 3, 4, 5, 6];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(5);
+        let checkpoint = slide.checkpoint();
+
+        slide.rewind(4);
+        slide.retain_remaining(|&x| x % 2 == 0);
+        slide.restore(checkpoint);
+    }
+
+    #[test]
+    #[should_panic = "cannot restore checkpoint at offset 1 as it is not a valid split boundary"]
+    fn restore_bad_boundary() {
+        let mut slide = Slide::new("é");
+
+        slide.restore(Checkpoint::new(1));
+    }
+}
@@ -0,0 +1,448 @@
+use core::{fmt, iter::FusedIterator};
+
+use crate::slide::{Slide, SlideMut};
+
+/// Panics if `size == 0`.
+#[inline(always)]
+#[track_caller]
+const fn assert_chunk_size(size: usize) {
+    assert!(size != 0, "chunk size must be non-zero");
+}
+
+/// An iterator that advances a borrowed [`Slide`] by `size` elements per item, yielding
+/// the chunks moved over.
+///
+/// The last chunk is shorter than `size` if the remaining region isn't a multiple of it.
+///
+/// See [`Slide::chunks`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<'s, 'a, T> {
+    /// The slide we're advancing.
+    slide: &'s mut Slide<'a, [T]>,
+    /// The size of each chunk.
+    size: usize,
+}
+
+impl<'s, 'a, T> Iterator for Chunks<'s, 'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        let len = self.slide.remaining_len();
+
+        if len == 0 {
+            return None;
+        }
+
+        // SAFETY: The amount is never greater than the length of the remaining region.
+        Some(unsafe { self.slide.advance_unchecked(self.size.min(len)) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<'s, 'a, T> ExactSizeIterator for Chunks<'s, 'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.remaining_len().div_ceil(self.size)
+    }
+}
+
+impl<'s, 'a, T> FusedIterator for Chunks<'s, 'a, T> {}
+
+/// An iterator that advances a borrowed [`Slide`] by exactly `size` elements per item,
+/// yielding the chunks moved over.
+///
+/// If the remaining region isn't a multiple of `size`, the cursor stops before the
+/// final, shorter chunk. It can be retrieved with [`ChunksExact::remainder`].
+///
+/// See [`Slide::chunks_exact`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksExact<'s, 'a, T> {
+    /// The slide we're advancing.
+    slide: &'s mut Slide<'a, [T]>,
+    /// The size of each chunk.
+    size: usize,
+}
+
+impl<'s, 'a, T> ChunksExact<'s, 'a, T> {
+    /// Returns the elements that will be left over once every chunk has been yielded.
+    #[inline]
+    #[must_use]
+    pub fn remainder(&self) -> &'a [T] {
+        let remaining = self.slide.remaining();
+
+        &remaining[remaining.len() - remaining.len() % self.size..]
+    }
+}
+
+impl<'s, 'a, T> Iterator for ChunksExact<'s, 'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.slide.remaining_len() < self.size {
+            return None;
+        }
+
+        // SAFETY: We just checked that there are at least `size` remaining elements.
+        Some(unsafe { self.slide.advance_unchecked(self.size) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<'s, 'a, T> ExactSizeIterator for ChunksExact<'s, 'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.remaining_len() / self.size
+    }
+}
+
+impl<'s, 'a, T> FusedIterator for ChunksExact<'s, 'a, T> {}
+
+/// An iterator that advances a borrowed [`SlideMut`] by `size` elements per item,
+/// yielding the chunks moved over mutably.
+///
+/// The last chunk is shorter than `size` if the remaining region isn't a multiple of it.
+///
+/// See [`SlideMut::chunks`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksMut<'s, 'a, T> {
+    /// The slide we're advancing.
+    slide: &'s mut SlideMut<'a, [T]>,
+    /// The size of each chunk.
+    size: usize,
+}
+
+impl<'s, 'a, T> Iterator for ChunksMut<'s, 'a, T> {
+    type Item = &'s mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'s mut [T]> {
+        let len = self.slide.remaining_len();
+
+        if len == 0 {
+            return None;
+        }
+
+        // SAFETY: The amount is never greater than the length of the remaining region.
+        let chunk: *mut [T] = unsafe { self.slide.advance_unchecked(self.size.min(len)) };
+
+        // SAFETY: The chunk now lies within the consumed region, which the slide cannot
+        //         touch for as long as it is mutably borrowed by us. Every chunk we yield
+        //         is disjoint, as the cursor only ever moves forward past them.
+        Some(unsafe { &mut *chunk })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<'s, 'a, T> ExactSizeIterator for ChunksMut<'s, 'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.remaining_len().div_ceil(self.size)
+    }
+}
+
+impl<'s, 'a, T> FusedIterator for ChunksMut<'s, 'a, T> {}
+
+impl<'s, 'a, T> fmt::Debug for ChunksMut<'s, 'a, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        // NOTE: The consumed region holds the chunks we have already yielded, which may
+        //       still be borrowed mutably, so we must not read it.
+        f.debug_struct("ChunksMut")
+            .field("remaining", &self.slide.remaining())
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// An iterator that advances a borrowed [`SlideMut`] by exactly `size` elements per item,
+/// yielding the chunks moved over mutably.
+///
+/// If the remaining region isn't a multiple of `size`, the cursor stops before the
+/// final, shorter chunk. It can be retrieved with [`ChunksExactMut::remainder`].
+///
+/// See [`SlideMut::chunks_exact`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksExactMut<'s, 'a, T> {
+    /// The slide we're advancing.
+    slide: &'s mut SlideMut<'a, [T]>,
+    /// The size of each chunk.
+    size: usize,
+}
+
+impl<'s, 'a, T> ChunksExactMut<'s, 'a, T> {
+    /// Returns the elements that will be left over once every chunk has been yielded.
+    #[inline]
+    #[must_use]
+    pub fn remainder(&self) -> &[T] {
+        let remaining = self.slide.remaining();
+
+        &remaining[remaining.len() - remaining.len() % self.size..]
+    }
+}
+
+impl<'s, 'a, T> Iterator for ChunksExactMut<'s, 'a, T> {
+    type Item = &'s mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'s mut [T]> {
+        if self.slide.remaining_len() < self.size {
+            return None;
+        }
+
+        // SAFETY: We just checked that there are at least `size` remaining elements.
+        let chunk: *mut [T] = unsafe { self.slide.advance_unchecked(self.size) };
+
+        // SAFETY: The chunk now lies within the consumed region, which the slide cannot
+        //         touch for as long as it is mutably borrowed by us. Every chunk we yield
+        //         is disjoint, as the cursor only ever moves forward past them.
+        Some(unsafe { &mut *chunk })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<'s, 'a, T> ExactSizeIterator for ChunksExactMut<'s, 'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.remaining_len() / self.size
+    }
+}
+
+impl<'s, 'a, T> FusedIterator for ChunksExactMut<'s, 'a, T> {}
+
+impl<'s, 'a, T> fmt::Debug for ChunksExactMut<'s, 'a, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        // NOTE: The consumed region holds the chunks we have already yielded, which may
+        //       still be borrowed mutably, so we must not read it.
+        f.debug_struct("ChunksExactMut")
+            .field("remaining", &self.slide.remaining())
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl<'a, T> Slide<'a, [T]> {
+    /// Returns an iterator that advances this slide by `size` elements per item, yielding
+    /// the chunks moved over.
+    ///
+    /// The last chunk is shorter than `size` if the remaining region isn't a multiple of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn chunks(
+        &mut self,
+        size: usize,
+    ) -> Chunks<'_, 'a, T> {
+        assert_chunk_size(size);
+
+        Chunks { slide: self, size }
+    }
+
+    /// Returns an iterator that advances this slide by exactly `size` elements per item,
+    /// yielding the chunks moved over.
+    ///
+    /// The cursor stops before the final chunk if it is shorter than `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn chunks_exact(
+        &mut self,
+        size: usize,
+    ) -> ChunksExact<'_, 'a, T> {
+        assert_chunk_size(size);
+
+        ChunksExact { slide: self, size }
+    }
+}
+
+impl<'a, T> SlideMut<'a, [T]> {
+    /// Returns an iterator that advances this slide by `size` elements per item, yielding
+    /// the chunks moved over mutably.
+    ///
+    /// The last chunk is shorter than `size` if the remaining region isn't a multiple of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn chunks(
+        &mut self,
+        size: usize,
+    ) -> ChunksMut<'_, 'a, T> {
+        assert_chunk_size(size);
+
+        ChunksMut { slide: self, size }
+    }
+
+    /// Returns an iterator that advances this slide by exactly `size` elements per item,
+    /// yielding the chunks moved over mutably.
+    ///
+    /// The cursor stops before the final chunk if it is shorter than `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn chunks_exact(
+        &mut self,
+        size: usize,
+    ) -> ChunksExactMut<'_, 'a, T> {
+        assert_chunk_size(size);
+
+        ChunksExactMut { slide: self, size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_uneven() {
+        let mut slide = Slide::new(&[1, 2, 3, 4, 5][..]);
+        let mut chunks = slide.chunks(2);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.next(), Some(&[1, 2][..]));
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.collect::<Vec<_>>(), [&[3, 4][..], &[5]]);
+        assert!(slide.is_at_end());
+        assert_eq!(slide.chunks(2).next(), None);
+    }
+
+    #[test]
+    fn chunks_exact_leaves_remainder() {
+        let mut slide = Slide::new(&[1, 2, 3, 4, 5][..]);
+        let chunks = slide.chunks_exact(2);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder(), [5]);
+        assert_eq!(chunks.collect::<Vec<_>>(), [&[1, 2][..], &[3, 4]]);
+        assert_eq!(slide.offset(), 4);
+        assert_eq!(slide.remaining(), [5]);
+    }
+
+    #[test]
+    fn chunks_dropped_early() {
+        let mut slide = Slide::new(&[1, 2, 3, 4, 5][..]);
+
+        assert_eq!(slide.chunks(3).next(), Some(&[1, 2, 3][..]));
+        assert_eq!(slide.offset(), 3);
+    }
+
+    #[test]
+    #[should_panic = "chunk size must be non-zero"]
+    fn chunks_zero_size() {
+        let mut slide = Slide::new(&[1, 2, 3][..]);
+
+        let _ = slide.chunks(0);
+    }
+
+    #[test]
+    fn chunks_mut_yields_disjoint_chunks() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut slide = SlideMut::new(&mut buf[..]);
+        let chunks: Vec<&mut [i32]> = slide.chunks(2).collect();
+
+        for chunk in chunks {
+            chunk.reverse();
+        }
+
+        assert!(slide.is_at_end());
+        assert_eq!(buf, [2, 1, 4, 3, 5]);
+    }
+
+    #[test]
+    fn chunks_exact_mut_leaves_remainder() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut slide = SlideMut::new(&mut buf[..]);
+        let mut chunks = slide.chunks_exact(2);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder(), [5]);
+
+        for chunk in &mut chunks {
+            chunk.fill(0);
+        }
+
+        assert_eq!(chunks.remainder(), [5]);
+        assert_eq!(slide.offset(), 4);
+        assert_eq!(buf, [0, 0, 0, 0, 5]);
+    }
+
+    #[test]
+    fn chunks_mut_debug_only_shows_remaining() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut slide = SlideMut::new(&mut buf[..]);
+        let mut chunks = slide.chunks(2);
+        let first = chunks.next().unwrap();
+
+        assert_eq!(
+            format!("{chunks:?}"),
+            "ChunksMut { remaining: [3, 4, 5], size: 2 }"
+        );
+
+        first[0] = 0;
+    }
+
+    #[test]
+    fn chunks_exact_mut_debug_only_shows_remaining() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut slide = SlideMut::new(&mut buf[..]);
+        let mut chunks = slide.chunks_exact(2);
+        let first = chunks.next().unwrap();
+
+        assert_eq!(
+            format!("{chunks:?}"),
+            "ChunksExactMut { remaining: [3, 4, 5], size: 2 }"
+        );
+
+        first[0] = 0;
+    }
+}
@@ -0,0 +1,105 @@
+use core::ffi::CStr;
+
+use crate::{memchr::memchr, slide::Slide};
+
+impl<'a> Slide<'a, [u8]> {
+    /// Create a new byte slide over the bytes of `s`, including its terminating NUL,
+    /// with the cursor at the start.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_cstr(s: &'a CStr) -> Slide<'a, [u8]> {
+        Slide::new(s.to_bytes_with_nul())
+    }
+
+    /// Returns the NUL-terminated string at the start of the remaining region without
+    /// moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(s)` upon success, where `s` includes its terminating NUL.
+    ///
+    /// - `None` if the remaining region does not contain a NUL.
+    #[inline]
+    #[must_use]
+    pub const fn peek_cstr(&self) -> Option<&'a CStr> {
+        let remaining = self.remaining();
+
+        match memchr(0, remaining) {
+            // SAFETY: The first `nul + 1` bytes end with a NUL, and contain no other NUL.
+            Some(nul) => {
+                Some(unsafe { CStr::from_bytes_with_nul_unchecked(remaining.split_at(nul + 1).0) })
+            }
+            None => None,
+        }
+    }
+
+    /// Advance the cursor past the NUL-terminated string at the start of the remaining
+    /// region, including its terminating NUL.
+    ///
+    /// This is useful for parsing sequences of NUL-terminated records.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(s)` upon success, where `s` includes its terminating NUL.
+    ///
+    /// - `None` if the remaining region does not contain a NUL, in which case the cursor
+    ///   is not moved.
+    #[inline]
+    pub const fn advance_cstr(&mut self) -> Option<&'a CStr> {
+        match self.peek_cstr() {
+            Some(s) => {
+                // SAFETY: `s` lies at the start of the remaining region.
+                unsafe { self.advance_unchecked(s.count_bytes() + 1) };
+
+                Some(s)
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_cstr_includes_nul() {
+        let slide = Slide::from_cstr(c"abc");
+
+        assert_eq!(slide.remaining(), b"abc\0");
+        assert_eq!(slide.offset(), 0);
+    }
+
+    #[test]
+    fn back_to_back_records() {
+        let mut slide = Slide::new(&b"one\0\0two\0"[..]);
+
+        assert_eq!(slide.peek_cstr(), Some(c"one"));
+        assert_eq!(slide.offset(), 0);
+        assert_eq!(slide.advance_cstr(), Some(c"one"));
+        assert_eq!(slide.advance_cstr(), Some(c""));
+        assert_eq!(slide.advance_cstr(), Some(c"two"));
+        assert!(slide.is_at_end());
+        assert_eq!(slide.advance_cstr(), None);
+    }
+
+    #[test]
+    fn interior_nul_ends_record() {
+        let mut slide = Slide::new(&b"ab\0cd\0"[..]);
+
+        assert_eq!(slide.advance_cstr(), Some(c"ab"));
+        assert_eq!(slide.offset(), 3);
+        assert_eq!(slide.remaining(), b"cd\0");
+    }
+
+    #[test]
+    fn missing_nul() {
+        let mut slide = Slide::new(&b"ab\0cd"[..]);
+
+        assert_eq!(slide.advance_cstr(), Some(c"ab"));
+        assert_eq!(slide.peek_cstr(), None);
+        assert_eq!(slide.advance_cstr(), None);
+        assert_eq!(slide.offset(), 3);
+        assert_eq!(slide.remaining(), b"cd");
+    }
+}
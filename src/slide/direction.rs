@@ -0,0 +1,1074 @@
+use crate::{
+    mem::NoDrop,
+    slice::{Slice, SplitError},
+    slide::{Slide, SlideMut},
+};
+
+/// A direction in which a slide can look or move, relative to its cursor.
+///
+/// This allows for writing code that is generic over which way it consumes a slide.
+///
+/// The default direction is [`Direction::Right`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Toward the start of the source, over the consumed region.
+    Left,
+    /// Toward the end of the source, over the remaining region.
+    #[default]
+    Right,
+}
+
+impl Direction {
+    /// Returns the opposite direction.
+    #[inline(always)]
+    #[must_use]
+    pub const fn opposite(self) -> Direction {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// Returns whether this is [`Direction::Right`], which is the direction a slide
+    /// advances in.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_forward(self) -> bool {
+        matches!(self, Direction::Right)
+    }
+
+    /// Returns whether this is [`Direction::Left`], which is the direction a slide
+    /// rewinds in.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_backward(self) -> bool {
+        matches!(self, Direction::Left)
+    }
+
+    /// Returns `forward` if this is [`Direction::Right`], and `backward` if this is
+    /// [`Direction::Left`].
+    ///
+    /// The value that is not returned is dropped.
+    #[inline(always)]
+    #[must_use]
+    pub fn select<T>(
+        self,
+        forward: T,
+        backward: T,
+    ) -> T {
+        match self {
+            Direction::Left => backward,
+            Direction::Right => forward,
+        }
+    }
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Attempt to peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `amount` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `amount` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `Err(error)` if it is invalid to peek by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_in(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        match dir {
+            Direction::Left => self.try_peek_back(amount),
+            Direction::Right => self.try_peek(amount),
+        }
+    }
+
+    /// Attempt to peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(peeked)` upon success, where `peeked` is the first `amount` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `amount` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `None` if it is invalid to peek by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn peek_in_checked(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> Option<&'a S> {
+        match NoDrop::new(self.try_peek_in(dir, amount)).transpose() {
+            Ok(peeked) => Some(peeked.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `amount` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `amount` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_in(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> &'a S {
+        match dir {
+            Direction::Left => self.peek_back(amount),
+            Direction::Right => self.peek(amount),
+        }
+    }
+
+    /// Peek `amount` elements in the direction of `dir` without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `amount` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `amount` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_in_unchecked(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> &'a S {
+        match dir {
+            // SAFETY: The caller ensures that it is valid to peek by `amount`.
+            Direction::Left => unsafe { self.peek_back_unchecked(amount) },
+            // SAFETY: The caller ensures that it is valid to peek by `amount`.
+            Direction::Right => unsafe { self.peek_unchecked(amount) },
+        }
+    }
+
+    /// Attempt to move the cursor by `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if it is invalid to move by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_slide(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        match dir {
+            Direction::Left => self.try_rewind(amount),
+            Direction::Right => self.try_advance(amount),
+        }
+    }
+
+    /// Attempt to move the cursor by `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `None` if it is invalid to move by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn slide_checked(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> Option<&'a S> {
+        match NoDrop::new(self.try_slide(dir, amount)).transpose() {
+            Ok(moved) => Some(moved.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Move the cursor by `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to move by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn slide(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> &'a S {
+        match dir {
+            Direction::Left => self.rewind(amount),
+            Direction::Right => self.advance(amount),
+        }
+    }
+
+    /// Move the cursor by `amount` elements in the direction of `dir` without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to move by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn slide_unchecked(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> &'a S {
+        match dir {
+            // SAFETY: The caller ensures that it is valid to move by `amount`.
+            Direction::Left => unsafe { self.rewind_unchecked(amount) },
+            // SAFETY: The caller ensures that it is valid to move by `amount`.
+            Direction::Right => unsafe { self.advance_unchecked(amount) },
+        }
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Attempt to peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `amount` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `amount` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `Err(error)` if it is invalid to peek by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_in(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> Result<&S, SplitError<S>> {
+        match dir {
+            Direction::Left => self.try_peek_back(amount),
+            Direction::Right => self.try_peek(amount),
+        }
+    }
+
+    /// Attempt to peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(peeked)` upon success, where `peeked` is the first `amount` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `amount` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `None` if it is invalid to peek by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn peek_in_checked(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> Option<&S> {
+        match NoDrop::new(self.try_peek_in(dir, amount)).transpose() {
+            Ok(peeked) => Some(peeked.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `amount` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `amount` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_in(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> &S {
+        match dir {
+            Direction::Left => self.peek_back(amount),
+            Direction::Right => self.peek(amount),
+        }
+    }
+
+    /// Peek `amount` elements in the direction of `dir` without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `amount` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `amount` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_in_unchecked(
+        &self,
+        dir: Direction,
+        amount: usize,
+    ) -> &S {
+        match dir {
+            // SAFETY: The caller ensures that it is valid to peek by `amount`.
+            Direction::Left => unsafe { self.peek_back_unchecked(amount) },
+            // SAFETY: The caller ensures that it is valid to peek by `amount`.
+            Direction::Right => unsafe { self.peek_unchecked(amount) },
+        }
+    }
+
+    /// Attempt to mutably peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `amount` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `amount` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `Err(error)` if it is invalid to peek by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_in_mut(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        match dir {
+            Direction::Left => self.try_peek_back_mut(amount),
+            Direction::Right => self.try_peek_mut(amount),
+        }
+    }
+
+    /// Attempt to mutably peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(peeked)` upon success, where `peeked` is the first `amount` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `amount` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `None` if it is invalid to peek by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn peek_in_mut_checked(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> Option<&mut S> {
+        match NoDrop::new(self.try_peek_in_mut(dir, amount)).transpose() {
+            Ok(peeked) => Some(peeked.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Mutably peek `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `amount` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `amount` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_in_mut(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> &mut S {
+        match dir {
+            Direction::Left => self.peek_back_mut(amount),
+            Direction::Right => self.peek_mut(amount),
+        }
+    }
+
+    /// Mutably peek `amount` elements in the direction of `dir` without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `amount` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `amount` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_in_mut_unchecked(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> &mut S {
+        match dir {
+            // SAFETY: The caller ensures that it is valid to peek by `amount`.
+            Direction::Left => unsafe { self.peek_back_mut_unchecked(amount) },
+            // SAFETY: The caller ensures that it is valid to peek by `amount`.
+            Direction::Right => unsafe { self.peek_mut_unchecked(amount) },
+        }
+    }
+
+    /// Attempt to move the cursor by `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if it is invalid to move by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_slide(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        match dir {
+            Direction::Left => self.try_rewind(amount),
+            Direction::Right => self.try_advance(amount),
+        }
+    }
+
+    /// Attempt to move the cursor by `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `None` if it is invalid to move by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn slide_checked(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> Option<&mut S> {
+        match NoDrop::new(self.try_slide(dir, amount)).transpose() {
+            Ok(moved) => Some(moved.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Move the cursor by `amount` elements in the direction of `dir`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to move by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn slide(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> &mut S {
+        match dir {
+            Direction::Left => self.rewind(amount),
+            Direction::Right => self.advance(amount),
+        }
+    }
+
+    /// Move the cursor by `amount` elements in the direction of `dir` without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to move by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn slide_unchecked(
+        &mut self,
+        dir: Direction,
+        amount: usize,
+    ) -> &mut S {
+        match dir {
+            // SAFETY: The caller ensures that it is valid to move by `amount`.
+            Direction::Left => unsafe { self.rewind_unchecked(amount) },
+            // SAFETY: The caller ensures that it is valid to move by `amount`.
+            Direction::Right => unsafe { self.advance_unchecked(amount) },
+        }
+    }
+}
+
+impl<'a, T> Slide<'a, [T]> {
+    /// Attempt to peek `N` elements in the direction of `dir`, as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `N` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `N` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `Err(error)` if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_array<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> Result<&'a [T; N], SplitError<[T]>> {
+        match self.try_peek_in(dir, N) {
+            // SAFETY: We know that `peeked` contains exactly `N` elements.
+            Ok(peeked) => Ok(unsafe { &*peeked.as_ptr().cast::<[T; N]>() }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Attempt to peek `N` elements in the direction of `dir`, as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(peeked)` upon success, where `peeked` is the first `N` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `N` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `None` if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn peek_array_checked<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> Option<&'a [T; N]> {
+        match self.try_peek_array(dir) {
+            Ok(peeked) => Some(peeked),
+            Err(..) => None,
+        }
+    }
+
+    /// Peek `N` elements in the direction of `dir`, as an array.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `N` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `N` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_array<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> &'a [T; N] {
+        let peeked = self.peek_in(dir, N);
+
+        // SAFETY: We know that `peeked` contains exactly `N` elements.
+        unsafe { &*peeked.as_ptr().cast::<[T; N]>() }
+    }
+
+    /// Peek `N` elements in the direction of `dir`, as an array, without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `N` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `N` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_array_unchecked<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> &'a [T; N] {
+        // SAFETY: The caller ensures that there are at least `N` elements in the direction of `dir`.
+        let peeked = unsafe { self.peek_in_unchecked(dir, N) };
+
+        // SAFETY: We know that `peeked` contains exactly `N` elements.
+        unsafe { &*peeked.as_ptr().cast::<[T; N]>() }
+    }
+
+    /// Attempt to move the cursor by `N` elements in the direction of `dir`, returning
+    /// the elements moved over as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if there are fewer than `N` elements in the direction of `dir`.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub const fn try_advance_array<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> Result<&'a [T; N], SplitError<[T]>> {
+        match self.try_slide(dir, N) {
+            // SAFETY: We know that `moved` contains exactly `N` elements.
+            Ok(moved) => Ok(unsafe { &*moved.as_ptr().cast::<[T; N]>() }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Attempt to move the cursor by `N` elements in the direction of `dir`, returning
+    /// the elements moved over as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `None` if there are fewer than `N` elements in the direction of `dir`.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn advance_array_checked<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> Option<&'a [T; N]> {
+        match self.try_advance_array(dir) {
+            Ok(moved) => Some(moved),
+            Err(..) => None,
+        }
+    }
+
+    /// Move the cursor by `N` elements in the direction of `dir`, returning the elements
+    /// moved over as an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub fn advance_array<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> &'a [T; N] {
+        let moved = self.slide(dir, N);
+
+        // SAFETY: We know that `moved` contains exactly `N` elements.
+        unsafe { &*moved.as_ptr().cast::<[T; N]>() }
+    }
+
+    /// Move the cursor by `N` elements in the direction of `dir` without any checks,
+    /// returning the elements moved over as an array.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_array_unchecked<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> &'a [T; N] {
+        // SAFETY: The caller ensures that there are at least `N` elements in the direction of `dir`.
+        let moved = unsafe { self.slide_unchecked(dir, N) };
+
+        // SAFETY: We know that `moved` contains exactly `N` elements.
+        unsafe { &*moved.as_ptr().cast::<[T; N]>() }
+    }
+}
+
+impl<'a, T> SlideMut<'a, [T]> {
+    /// Attempt to peek `N` elements in the direction of `dir`, as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `N` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `N` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `Err(error)` if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_array<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> Result<&[T; N], SplitError<[T]>> {
+        match self.try_peek_in(dir, N) {
+            // SAFETY: We know that `peeked` contains exactly `N` elements.
+            Ok(peeked) => Ok(unsafe { &*peeked.as_ptr().cast::<[T; N]>() }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Attempt to peek `N` elements in the direction of `dir`, as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(peeked)` upon success, where `peeked` is the first `N` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `N` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `None` if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn peek_array_checked<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> Option<&[T; N]> {
+        match self.try_peek_array(dir) {
+            Ok(peeked) => Some(peeked),
+            Err(..) => None,
+        }
+    }
+
+    /// Peek `N` elements in the direction of `dir`, as an array.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `N` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `N` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_array<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> &[T; N] {
+        let peeked = self.peek_in(dir, N);
+
+        // SAFETY: We know that `peeked` contains exactly `N` elements.
+        unsafe { &*peeked.as_ptr().cast::<[T; N]>() }
+    }
+
+    /// Peek `N` elements in the direction of `dir`, as an array, without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `N` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `N` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_array_unchecked<const N: usize>(
+        &self,
+        dir: Direction,
+    ) -> &[T; N] {
+        // SAFETY: The caller ensures that there are at least `N` elements in the direction of `dir`.
+        let peeked = unsafe { self.peek_in_unchecked(dir, N) };
+
+        // SAFETY: We know that `peeked` contains exactly `N` elements.
+        unsafe { &*peeked.as_ptr().cast::<[T; N]>() }
+    }
+
+    /// Attempt to peek `N` elements in the direction of `dir`, as a mutable array.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `N` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `N` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `Err(error)` if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_array_mut<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> Result<&mut [T; N], SplitError<[T]>> {
+        match self.try_peek_in_mut(dir, N) {
+            // SAFETY: We know that `peeked` contains exactly `N` elements.
+            Ok(peeked) => Ok(unsafe { &mut *peeked.as_mut_ptr().cast::<[T; N]>() }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Attempt to peek `N` elements in the direction of `dir`, as a mutable array.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(peeked)` upon success, where `peeked` is the first `N` elements of the
+    ///   remaining region if `dir` is [`Direction::Right`], or the last `N` elements of
+    ///   the consumed region if `dir` is [`Direction::Left`].
+    ///
+    /// - `None` if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn peek_array_mut_checked<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> Option<&mut [T; N]> {
+        match self.try_peek_array_mut(dir) {
+            Ok(peeked) => Some(peeked),
+            Err(..) => None,
+        }
+    }
+
+    /// Peek `N` elements in the direction of `dir`, as a mutable array.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `N` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `N` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_array_mut<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> &mut [T; N] {
+        let peeked = self.peek_in_mut(dir, N);
+
+        // SAFETY: We know that `peeked` contains exactly `N` elements.
+        unsafe { &mut *peeked.as_mut_ptr().cast::<[T; N]>() }
+    }
+
+    /// Peek `N` elements in the direction of `dir`, as a mutable array, without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first `N` elements of the remaining region if `dir` is
+    /// [`Direction::Right`], or the last `N` elements of the consumed region if `dir`
+    /// is [`Direction::Left`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `N` elements in the direction of `dir`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_array_mut_unchecked<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> &mut [T; N] {
+        // SAFETY: The caller ensures that there are at least `N` elements in the direction of `dir`.
+        let peeked = unsafe { self.peek_in_mut_unchecked(dir, N) };
+
+        // SAFETY: We know that `peeked` contains exactly `N` elements.
+        unsafe { &mut *peeked.as_mut_ptr().cast::<[T; N]>() }
+    }
+
+    /// Attempt to move the cursor by `N` elements in the direction of `dir`, returning
+    /// the elements moved over as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if there are fewer than `N` elements in the direction of `dir`.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub const fn try_advance_array<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> Result<&mut [T; N], SplitError<[T]>> {
+        match self.try_slide(dir, N) {
+            // SAFETY: We know that `moved` contains exactly `N` elements.
+            Ok(moved) => Ok(unsafe { &mut *moved.as_mut_ptr().cast::<[T; N]>() }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Attempt to move the cursor by `N` elements in the direction of `dir`, returning
+    /// the elements moved over as an array.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(moved)` upon success, where `moved` is the region the cursor moved over.
+    ///
+    /// - `None` if there are fewer than `N` elements in the direction of `dir`.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn advance_array_checked<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> Option<&mut [T; N]> {
+        match self.try_advance_array(dir) {
+            Ok(moved) => Some(moved),
+            Err(..) => None,
+        }
+    }
+
+    /// Move the cursor by `N` elements in the direction of `dir`, returning the elements
+    /// moved over as an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub fn advance_array<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> &mut [T; N] {
+        let moved = self.slide(dir, N);
+
+        // SAFETY: We know that `moved` contains exactly `N` elements.
+        unsafe { &mut *moved.as_mut_ptr().cast::<[T; N]>() }
+    }
+
+    /// Move the cursor by `N` elements in the direction of `dir` without any checks,
+    /// returning the elements moved over as an array.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `N` elements in the direction of `dir`.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_array_unchecked<const N: usize>(
+        &mut self,
+        dir: Direction,
+    ) -> &mut [T; N] {
+        // SAFETY: The caller ensures that there are at least `N` elements in the direction of `dir`.
+        let moved = unsafe { self.slide_unchecked(dir, N) };
+
+        // SAFETY: We know that `moved` contains exactly `N` elements.
+        unsafe { &mut *moved.as_mut_ptr().cast::<[T; N]>() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slide_mut_peek_array() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(2);
+
+        assert_eq!(slide.try_peek_array::<3>(Direction::Right), Ok(&[3, 4, 5]));
+        assert_eq!(slide.peek_array::<2>(Direction::Left), &[1, 2]);
+        assert_eq!(slide.peek_array_checked::<3>(Direction::Left), None);
+        assert_eq!(slide.peek_array_checked::<0>(Direction::Left), Some(&[]));
+        // SAFETY: There are three remaining elements.
+        assert_eq!(
+            unsafe { slide.peek_array_unchecked::<1>(Direction::Right) },
+            &[3]
+        );
+        assert!(slide.try_peek_array::<4>(Direction::Right).is_err());
+        assert_eq!(slide.offset(), 2);
+    }
+
+    #[test]
+    fn slide_mut_peek_array_mut() {
+        let mut buf = [1, 2, 3, 4, 5];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(2);
+
+        *slide.peek_array_mut::<2>(Direction::Right) = [30, 40];
+        slide.peek_array_mut_checked::<1>(Direction::Left).unwrap()[0] = 20;
+        slide.try_peek_array_mut::<1>(Direction::Left).unwrap()[0] += 1;
+        // SAFETY: There are two consumed elements.
+        unsafe { slide.peek_array_mut_unchecked::<2>(Direction::Left)[0] = 10 };
+
+        assert!(
+            slide
+                .peek_array_mut_checked::<4>(Direction::Right)
+                .is_none()
+        );
+        assert_eq!(slide.offset(), 2);
+        assert_eq!(buf, [10, 21, 30, 40, 5]);
+    }
+
+    #[test]
+    fn slide_and_slide_mut_agree() {
+        let mut buf = [1u8, 2, 3, 4];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(1);
+
+        let shared = slide.as_slide();
+
+        for dir in [Direction::Left, Direction::Right] {
+            assert_eq!(
+                shared.peek_array_checked::<1>(dir),
+                slide.peek_array_checked::<1>(dir)
+            );
+            assert_eq!(
+                shared.peek_array_checked::<3>(dir),
+                slide.peek_array_checked::<3>(dir)
+            );
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use core::{error::Error, fmt};
+
+use crate::{
+    slice::Slice,
+    slide::{Slide, SlideMut},
+};
+
+/// An error that occurs when a slide was expected to be exhausted, yet it still has
+/// remaining elements.
+///
+/// See [`Slide::expect_eof`] and [`SlideMut::expect_eof`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrailingData {
+    /// The amount of elements that were left over.
+    remaining: usize,
+}
+
+impl TrailingData {
+    /// Create a new [`TrailingData`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(remaining: usize) -> TrailingData {
+        TrailingData { remaining }
+    }
+
+    /// Returns the amount of elements that were left over.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl fmt::Display for TrailingData {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "expected end of input, found {} trailing",
+            self.remaining
+        )
+    }
+}
+
+impl Error for TrailingData {}
+
+/// Returns an error if `remaining` is nonzero.
+#[inline(always)]
+const fn expect_eof(remaining: usize) -> Result<(), TrailingData> {
+    if remaining == 0 {
+        Ok(())
+    } else {
+        Err(TrailingData::new(remaining))
+    }
+}
+
+/// Panics with the error for `remaining` trailing elements.
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn eof_failed(
+    offset: usize,
+    remaining: usize,
+) -> ! {
+    panic!("assert_eof() failed at offset {offset}: {remaining} elements remaining")
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Ensure that there are no remaining elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the slide is exhausted.
+    ///
+    /// - `Err(error)` with the amount of remaining elements otherwise.
+    #[inline]
+    pub const fn expect_eof(&self) -> Result<(), TrailingData> {
+        expect_eof(self.remaining_len())
+    }
+
+    /// Assert that there are no remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slide is not exhausted.
+    #[inline]
+    #[track_caller]
+    pub fn assert_eof(&self) {
+        if !self.is_exhausted() {
+            eof_failed(self.offset(), self.remaining_len())
+        }
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Ensure that there are no remaining elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the slide is exhausted.
+    ///
+    /// - `Err(error)` with the amount of remaining elements otherwise.
+    #[inline]
+    pub const fn expect_eof(&self) -> Result<(), TrailingData> {
+        self.as_slide().expect_eof()
+    }
+
+    /// Assert that there are no remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slide is not exhausted.
+    #[inline]
+    #[track_caller]
+    pub fn assert_eof(&self) {
+        self.as_slide().assert_eof()
+    }
+}
@@ -0,0 +1,101 @@
+use core::{error::Error, fmt};
+
+use crate::{
+    slice::Slice,
+    slide::{Slide, SlideMut},
+};
+
+/// An error describing how a slide violates its invariants.
+///
+/// See [`Slide::validate`] and [`SlideMut::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SlideInvariantError {
+    /// The end of the slide does not lie a whole amount of elements at or after its start.
+    MisorderedEnd,
+    /// The cursor does not lie a whole amount of elements at or after the start.
+    MisorderedCursor,
+    /// The cursor lies past the end of the slide.
+    CursorOutOfBounds {
+        /// The offset of the cursor.
+        offset: usize,
+        /// The length of the source.
+        len: usize,
+    },
+    /// The cursor does not lie on a valid split boundary for the source.
+    CursorNotOnBoundary {
+        /// The offset of the cursor.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for SlideInvariantError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            SlideInvariantError::MisorderedEnd => f.write_str("end of slide lies before its start"),
+            SlideInvariantError::MisorderedCursor => {
+                f.write_str("cursor of slide lies before its start")
+            }
+            SlideInvariantError::CursorOutOfBounds { offset, len } => {
+                write!(f, "cursor offset {offset} is out of bounds (len {len})")
+            }
+            SlideInvariantError::CursorNotOnBoundary { offset } => {
+                write!(f, "cursor offset {offset} is not on a valid split boundary")
+            }
+        }
+    }
+}
+
+impl Error for SlideInvariantError {}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Check that this slide upholds its invariants, returning a descriptive error
+    /// instead of relying on them.
+    ///
+    /// This is intended for testing slides that were constructed through unsafe
+    /// means, such as [`Slide::from_nonnull`].
+    ///
+    /// The memory of the source is only inspected once the start, cursor and end of the
+    /// slide are known to be in order. At that point, it must still be valid for `'a`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if every invariant holds.
+    ///
+    /// - `Err(error)` describing the first invariant found to be violated.
+    #[inline]
+    pub fn validate(&self) -> Result<(), SlideInvariantError> {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        unsafe { self.into_raw().validate() }
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Check that this slide upholds its invariants, returning a descriptive error
+    /// instead of relying on them.
+    ///
+    /// This is intended for testing slides that were constructed through unsafe
+    /// means, such as [`SlideMut::from_nonnull`].
+    ///
+    /// The memory of the source is only inspected once the start, cursor and end of the
+    /// slide are known to be in order. At that point, it must still be valid for `'a`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if every invariant holds.
+    ///
+    /// - `Err(error)` describing the first invariant found to be violated.
+    #[inline]
+    pub fn validate(&self) -> Result<(), SlideInvariantError> {
+        self.as_slide().validate()
+    }
+}
@@ -0,0 +1,100 @@
+use std::io;
+
+use crate::slide::{Slide, SlideMut};
+
+impl<'a> io::Read for Slide<'a, [u8]> {
+    /// Copies from the remaining region into `buf`, advancing the cursor past the bytes copied.
+    #[inline]
+    fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> io::Result<usize> {
+        let amount = buf.len().min(self.remaining_len());
+
+        // SAFETY: We know that `amount <= remaining_len`.
+        let read = unsafe { self.advance_unchecked(amount) };
+
+        buf[..amount].copy_from_slice(read);
+
+        Ok(amount)
+    }
+
+    /// Fills `buf` entirely from the remaining region.
+    ///
+    /// Upon failure, the cursor is left untouched.
+    #[inline]
+    fn read_exact(
+        &mut self,
+        buf: &mut [u8],
+    ) -> io::Result<()> {
+        match self.try_advance(buf.len()) {
+            Ok(read) => {
+                buf.copy_from_slice(read);
+
+                Ok(())
+            }
+            Err(..) => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+impl<'a> io::Read for SlideMut<'a, [u8]> {
+    /// Copies from the remaining region into `buf`, advancing the cursor past the bytes copied.
+    #[inline]
+    fn read(
+        &mut self,
+        buf: &mut [u8],
+    ) -> io::Result<usize> {
+        let amount = buf.len().min(self.remaining_len());
+
+        // SAFETY: We know that `amount <= remaining_len`.
+        let read = unsafe { self.advance_unchecked(amount) };
+
+        buf[..amount].copy_from_slice(read);
+
+        Ok(amount)
+    }
+
+    /// Fills `buf` entirely from the remaining region.
+    ///
+    /// Upon failure, the cursor is left untouched.
+    #[inline]
+    fn read_exact(
+        &mut self,
+        buf: &mut [u8],
+    ) -> io::Result<()> {
+        match self.try_advance(buf.len()) {
+            Ok(read) => {
+                buf.copy_from_slice(read);
+
+                Ok(())
+            }
+            Err(..) => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+impl<'a> io::Write for SlideMut<'a, [u8]> {
+    /// Copies from `buf` into the remaining region, advancing the cursor past the bytes written.
+    ///
+    /// Once the remaining region is exhausted, this returns `Ok(0)`.
+    #[inline]
+    fn write(
+        &mut self,
+        buf: &[u8],
+    ) -> io::Result<usize> {
+        let amount = buf.len().min(self.remaining_len());
+
+        // SAFETY: We know that `amount <= remaining_len`.
+        let written = unsafe { self.advance_unchecked(amount) };
+
+        written.copy_from_slice(&buf[..amount]);
+
+        Ok(amount)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
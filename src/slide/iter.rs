@@ -0,0 +1,529 @@
+use core::{iter::FusedIterator, slice};
+
+use crate::{
+    slice::Slice,
+    slide::{Slide, SlideMut},
+};
+
+/// An iterator over the remaining elements of a [`Slide`], advancing the slide
+/// by one element per item.
+///
+/// See [`Slide::iter_remaining`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterRemaining<'a, T> {
+    /// The slide we're iterating over.
+    slide: Slide<'a, [T]>,
+}
+
+impl<'a, T> IterRemaining<'a, T> {
+    /// Create a new [`IterRemaining`].
+    #[inline(always)]
+    pub(crate) const fn new(slide: Slide<'a, [T]>) -> IterRemaining<'a, T> {
+        IterRemaining { slide }
+    }
+
+    /// Returns the underlying slide, with its cursor after the last element yielded from
+    /// the front, and its end before the last element yielded from the back.
+    #[inline(always)]
+    #[must_use]
+    pub const fn slide(&self) -> Slide<'a, [T]> {
+        self.slide
+    }
+
+    /// Returns the elements that have yet to be yielded.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [T] {
+        self.slide.remaining()
+    }
+}
+
+impl<'a, T> Clone for IterRemaining<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        IterRemaining { slide: self.slide }
+    }
+}
+
+impl<'a, T> Iterator for IterRemaining<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        match self.slide.try_advance(1) {
+            Ok([elem]) => Some(elem),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterRemaining<'a, T> {
+    /// Yields the last remaining element.
+    ///
+    /// This moves the end of the underlying slide rather than its cursor, so the remaining
+    /// region shrinks by one element from the back, just as [`Iterator::next`] shrinks it
+    /// by one element from the front. Once the two meet, nothing more is yielded.
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        let remaining = self.slide.remaining();
+        let (last, rest) = remaining.split_last()?;
+
+        // SAFETY: `rest.len() < remaining.len()`, and any index is a valid split boundary
+        //         for `[T]`.
+        unsafe { self.slide.truncate_remaining_unchecked(rest.len()) };
+
+        Some(last)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterRemaining<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.remaining().len()
+    }
+}
+
+impl<'a, T> FusedIterator for IterRemaining<'a, T> {}
+
+/// An iterator over elements of a [`Slide`] paired with their index in the source.
+///
+/// See [`Slide::iter_remaining_indexed`] and [`Slide::iter_consumed_indexed`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Indexed<'a, T> {
+    /// The elements that have yet to be yielded.
+    iter: slice::Iter<'a, T>,
+    /// The source index of the next element yielded from the front.
+    index: usize,
+}
+
+impl<'a, T> Indexed<'a, T> {
+    /// Create a new [`Indexed`] where the first element of `elems` lies at `index`.
+    #[inline(always)]
+    pub(crate) fn new(
+        elems: &'a [T],
+        index: usize,
+    ) -> Indexed<'a, T> {
+        Indexed {
+            iter: elems.iter(),
+            index,
+        }
+    }
+
+    /// Returns the elements that have yet to be yielded.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.iter.as_slice()
+    }
+}
+
+impl<'a, T> Clone for Indexed<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Indexed {
+            iter: self.iter.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Indexed<'a, T> {
+    type Item = (usize, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, &'a T)> {
+        let elem = self.iter.next()?;
+        let index = self.index;
+
+        self.index += 1;
+
+        Some((index, elem))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Indexed<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a T)> {
+        let elem = self.iter.next_back()?;
+
+        // NOTE: `elem` was the last element, so its index is offset by the amount of elements
+        //       that still precede it.
+        Some((self.index + self.iter.len(), elem))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Indexed<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T> FusedIterator for Indexed<'a, T> {}
+
+/// An iterator that advances a borrowed [`Slide`] by one element per item, yielding the
+/// elements moved over.
+///
+/// For [`prim@str`] slides, this yields [`char`]s instead.
+///
+/// See [`Slide::iter_forward`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterForward<'s, 'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're advancing.
+    slide: &'s mut Slide<'a, S>,
+}
+
+impl<'s, 'a, T> Iterator for IterForward<'s, 'a, [T]> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        match self.slide.try_advance(1) {
+            Ok([elem]) => Some(elem),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<'s, 'a, T> ExactSizeIterator for IterForward<'s, 'a, [T]> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.remaining().len()
+    }
+}
+
+impl<'s, 'a, T> FusedIterator for IterForward<'s, 'a, [T]> {}
+
+impl<'s, 'a> Iterator for IterForward<'s, 'a, str> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let ch = self.slide.remaining().chars().next()?;
+
+        // SAFETY: The end of a `char` within a `str` always lies on a char boundary.
+        unsafe { self.slide.advance_unchecked(ch.len_utf8()) };
+
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slide.remaining().len();
+
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+impl<'s, 'a> FusedIterator for IterForward<'s, 'a, str> {}
+
+/// An iterator that rewinds a borrowed [`Slide`] by one element per item, yielding the
+/// elements moved over.
+///
+/// For [`prim@str`] slides, this yields [`char`]s instead.
+///
+/// See [`Slide::iter_backward`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterBackward<'s, 'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're rewinding.
+    slide: &'s mut Slide<'a, S>,
+}
+
+impl<'s, 'a, T> Iterator for IterBackward<'s, 'a, [T]> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        match self.slide.try_rewind(1) {
+            Ok([elem]) => Some(elem),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<'s, 'a, T> ExactSizeIterator for IterBackward<'s, 'a, [T]> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.slide.consumed().len()
+    }
+}
+
+impl<'s, 'a, T> FusedIterator for IterBackward<'s, 'a, [T]> {}
+
+impl<'s, 'a> Iterator for IterBackward<'s, 'a, str> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let ch = self.slide.consumed().chars().next_back()?;
+
+        // SAFETY: The start of a `char` within a `str` always lies on a char boundary.
+        unsafe { self.slide.rewind_unchecked(ch.len_utf8()) };
+
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slide.consumed().len();
+
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+impl<'s, 'a> FusedIterator for IterBackward<'s, 'a, str> {}
+
+impl<'a> Slide<'a, str> {
+    /// Returns an iterator that advances this slide by one [`char`] per item, yielding
+    /// the [`char`]s moved over.
+    ///
+    /// The slide can be inspected once the iterator is dropped, which allows for
+    /// interleaving iteration with other movement.
+    #[inline(always)]
+    pub const fn iter_forward(&mut self) -> IterForward<'_, 'a, str> {
+        IterForward { slide: self }
+    }
+
+    /// Returns an iterator that rewinds this slide by one [`char`] per item, yielding
+    /// the [`char`]s moved over.
+    ///
+    /// The slide can be inspected once the iterator is dropped, which allows for
+    /// interleaving iteration with other movement.
+    #[inline(always)]
+    pub const fn iter_backward(&mut self) -> IterBackward<'_, 'a, str> {
+        IterBackward { slide: self }
+    }
+}
+
+impl<'a, T> Slide<'a, [T]> {
+    /// Returns an iterator over the remaining elements, advancing the slide by one
+    /// element per item.
+    ///
+    /// The iterator reports its exact length, which is always the length of the
+    /// remaining region.
+    #[inline(always)]
+    pub const fn iter_remaining(self) -> IterRemaining<'a, T> {
+        IterRemaining::new(self)
+    }
+
+    /// Returns an iterator that advances this slide by one element per item, yielding
+    /// the elements moved over.
+    ///
+    /// Unlike [`Slide::iter_remaining`], this borrows the slide, which can be inspected
+    /// once the iterator is dropped.
+    #[inline(always)]
+    pub const fn iter_forward(&mut self) -> IterForward<'_, 'a, [T]> {
+        IterForward { slide: self }
+    }
+
+    /// Returns an iterator that rewinds this slide by one element per item, yielding
+    /// the elements moved over.
+    ///
+    /// The slide can be inspected once the iterator is dropped.
+    #[inline(always)]
+    pub const fn iter_backward(&mut self) -> IterBackward<'_, 'a, [T]> {
+        IterBackward { slide: self }
+    }
+
+    /// Returns an iterator over the remaining elements that does *not* move the cursor.
+    ///
+    /// This is useful for making lookahead decisions.
+    #[inline(always)]
+    pub fn peek_iter(&self) -> slice::Iter<'a, T> {
+        self.remaining().iter()
+    }
+
+    /// Returns an iterator over the overlapping windows of `size` elements within the
+    /// remaining region that does *not* move the cursor.
+    ///
+    /// The windows are taken from the remaining region at the time of the call, so later
+    /// movement of this slide is not reflected by the iterator. If `size` is greater than
+    /// the length of the remaining region, nothing is yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`.
+    #[inline]
+    #[track_caller]
+    pub fn windows(
+        &self,
+        size: usize,
+    ) -> slice::Windows<'a, T> {
+        self.remaining().windows(size)
+    }
+
+    /// Returns an iterator over the remaining elements paired with their index in the
+    /// source, without moving the cursor.
+    ///
+    /// The first element yielded has an index of [`Slide::offset`].
+    #[inline(always)]
+    pub fn iter_remaining_indexed(&self) -> Indexed<'a, T> {
+        Indexed::new(self.remaining(), self.offset())
+    }
+
+    /// Returns an iterator over the consumed elements paired with their index in the
+    /// source, without moving the cursor.
+    ///
+    /// The first element yielded has an index of `0`.
+    #[inline(always)]
+    pub fn iter_consumed_indexed(&self) -> Indexed<'a, T> {
+        Indexed::new(self.consumed(), 0)
+    }
+
+    /// Convert this slide into a [`slice::Iter`] over the remaining elements.
+    #[inline(always)]
+    pub fn into_iter_remaining(self) -> slice::Iter<'a, T> {
+        self.remaining().iter()
+    }
+
+    /// Rebuild a slide over `source` from a [`slice::Iter`] that was created from `source`.
+    ///
+    /// The cursor is placed where `iter` would yield its next element. Any elements that
+    /// were consumed from the back of `iter` are considered remaining.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(slide)` if the elements yet to be yielded by `iter` lie within `source`.
+    ///
+    /// - `None` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn from_iter_and_source(
+        iter: &slice::Iter<'a, T>,
+        source: &'a [T],
+    ) -> Option<Slide<'a, [T]>> {
+        let rest = iter.as_slice();
+
+        let source_range = source.as_ptr_range();
+        let rest_range = rest.as_ptr_range();
+
+        if rest_range.start < source_range.start || rest_range.end > source_range.end {
+            return None;
+        }
+
+        // NOTE: Zero sized types all share the same address, so we can't do better than
+        //       assuming that `rest` is a suffix of `source`.
+        let offset = if size_of::<T>() == 0 {
+            source.len().checked_sub(rest.len())?
+        } else {
+            // SAFETY: We just checked that `rest` lies within `source`.
+            unsafe { rest_range.start.offset_from_unsigned(source_range.start) }
+        };
+
+        // SAFETY: We know that `offset <= source.len()`.
+        Some(unsafe { Slide::with_offset_unchecked(source, offset) })
+    }
+}
+
+impl<'a, T> SlideMut<'a, [T]> {
+    /// Convert this slide into a [`slice::IterMut`] over the remaining elements.
+    #[inline(always)]
+    pub fn into_iter_remaining(self) -> slice::IterMut<'a, T> {
+        self.into_remaining().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::slide::Slide;
+
+    #[test]
+    fn iter_remaining_interleaved() {
+        let mut slide = Slide::new(&[1, 2, 3, 4, 5, 6][..]);
+        slide.advance(1);
+
+        let mut iter = slide.iter_remaining();
+
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.as_slice(), [3, 4, 5]);
+
+        let rest = iter.slide();
+
+        assert_eq!(rest.offset(), 2);
+        assert_eq!(rest.consumed(), [1, 2]);
+        assert_eq!(rest.remaining(), [3, 4, 5]);
+        assert_eq!(rest.source(), [1, 2, 3, 4, 5]);
+
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let rest = iter.slide();
+
+        assert_eq!(rest.offset(), 3);
+        assert!(rest.is_at_end());
+        assert_eq!(rest.source(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn iter_remaining_rev() {
+        let slide = Slide::new(&[1, 2, 3][..]);
+
+        assert!(slide.iter_remaining().rev().eq(&[3, 2, 1]));
+        assert_eq!(slide.iter_remaining().rev().len(), 3);
+    }
+
+    #[test]
+    fn iter_remaining_back_only() {
+        let mut iter = Slide::new(&[1, 2][..]).iter_remaining();
+
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&1));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.slide().offset(), 0);
+        assert!(iter.slide().source().is_empty());
+    }
+}
@@ -184,6 +184,39 @@ where
         *self = unsafe { self.rewind(amount) };
     }
 
+    /// Calculate the offset from `origin` until `self`, without assuming anything about
+    /// either location.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(offset)` if `self` lies at or after `origin`, and is a whole amount of
+    ///   elements away from it.
+    ///
+    /// - `None` otherwise.
+    #[inline]
+    #[must_use]
+    pub(crate) fn checked_offset_from(
+        self,
+        origin: NonNull<S::Elem>,
+    ) -> Option<usize> {
+        if Self::INDEX_BASED {
+            // SAFETY: We're index based, so `index` is the active field.
+            Some(unsafe { self.index })
+        } else {
+            // SAFETY: We're pointer based, so `ptr` is the active field.
+            let bytes = unsafe { self.ptr }
+                .addr()
+                .get()
+                .checked_sub(origin.addr().get())?;
+
+            // NOTE: Zero sized types are always index based, so this can't divide by zero.
+            match bytes % size_of::<S::Elem>() {
+                0 => Some(bytes / size_of::<S::Elem>()),
+                _ => None,
+            }
+        }
+    }
+
     /// Apply this location to a given pointer.
     ///
     /// # Safety
@@ -0,0 +1,6 @@
+/// Trait to seal what types we're considering to be delimiters for `S`.
+pub trait Sealed<S>
+where
+    S: ?Sized,
+{
+}
@@ -6,7 +6,7 @@ use crate::{
     slice::{
         OobIndex, Slice, SplitError, len, raw_slice_nonnull, split_error_handler, validate_split_at,
     },
-    slide::location::Location,
+    slide::{SlideInvariantError, location::Location},
     util::cmp_usize,
 };
 
@@ -215,6 +215,62 @@ where
         };
     }
 
+    /// Check the invariants of this slide without relying on them.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that, if the locations of this slide are in order, then
+    /// `start..end` is a valid, initialized `S`.
+    #[inline]
+    pub(crate) unsafe fn validate(&self) -> Result<(), SlideInvariantError> {
+        let end = self
+            .end
+            .checked_offset_from(self.start)
+            .ok_or(SlideInvariantError::MisorderedEnd)?;
+
+        let offset = self
+            .cursor
+            .checked_offset_from(self.start)
+            .ok_or(SlideInvariantError::MisorderedCursor)?;
+
+        if offset > end {
+            return Err(SlideInvariantError::CursorOutOfBounds { offset, len: end });
+        }
+
+        // SAFETY: The locations are in order, so the caller ensures that the entire slice is valid.
+        let entire = unsafe { self.entire_ref() };
+
+        match validate_split_at(entire, offset) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(SlideInvariantError::CursorNotOnBoundary { offset }),
+        }
+    }
+
+    /// Shorten the remaining region to `len` elements by moving the end of the slide.
+    ///
+    /// The elements past the new end are left untouched in the underlying buffer, but
+    /// are no longer considered to be part of the slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `len` is less than or equal to the length of the remaining
+    /// region, and that `cursor + len` lies on a valid split boundary for `S`.
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) const unsafe fn truncate_remaining_unchecked(
+        &mut self,
+        len: usize,
+    ) {
+        // SAFETY: The caller ensures that `len <= remaining_len`.
+        unsafe { assert_unchecked!(len <= self.remaining_len(), "`len > remaining_len`") };
+
+        // SAFETY: The caller ensures that `cursor + len` lies within `cursor..=end`, and on a
+        //         valid split boundary for `S`.
+        self.end = unsafe { self.cursor.advance(len) };
+
+        self.compiler_hints();
+    }
+
     /// Returns a raw pointer into the entire slice.
     ///
     /// # Safety
@@ -1003,6 +1059,87 @@ where
     }
 }
 
+/// A cursor movement that can fail, used for reporting panics with context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Movement {
+    /// Creating a slide with its cursor at an offset.
+    WithOffset,
+    /// Peeking ahead of the cursor.
+    Peek,
+    /// Peeking behind the cursor.
+    PeekBack,
+    /// Advancing the cursor.
+    Advance,
+    /// Rewinding the cursor.
+    Rewind,
+}
+
+impl Movement {
+    /// Returns the name of the method that performs this movement.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Movement::WithOffset => "with_offset",
+            Movement::Peek => "peek",
+            Movement::PeekBack => "peek_back",
+            Movement::Advance => "advance",
+            Movement::Rewind => "rewind",
+        }
+    }
+
+    /// Returns whether this movement goes toward the end of the slide.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn is_forward(self) -> bool {
+        matches!(
+            self,
+            Movement::WithOffset | Movement::Peek | Movement::Advance
+        )
+    }
+}
+
+impl<S> RawSlide<S>
+where
+    S: Slice + ?Sized,
+{
+    /// Panics with an error message describing why `movement` by `amount` elements failed,
+    /// including the offset of the cursor and the length of the slide.
+    ///
+    /// # Note
+    ///
+    /// This is not a `const fn`, as it is currently impossible to format panic messages
+    /// from `const`.
+    #[inline(never)]
+    #[track_caller]
+    #[cold]
+    pub(crate) fn context_panic(
+        &self,
+        movement: Movement,
+        amount: usize,
+        error: SplitError<S>,
+    ) -> ! {
+        let name = movement.name();
+        let offset = self.consumed_len();
+        let len = self.entire_len();
+
+        let (available, region) = if movement.is_forward() {
+            (self.remaining_len(), "remaining")
+        } else {
+            (offset, "consumed")
+        };
+
+        match error {
+            SplitError::OutOfBounds { .. } => panic!(
+                "{name}({amount}) but only {available} {region} at offset {offset} (len {len})"
+            ),
+            SplitError::Other(..) => {
+                panic!("{name}({amount}) failed at offset {offset} (len {len}): {error}")
+            }
+        }
+    }
+}
+
 impl<S> Clone for RawSlide<S>
 where
     S: Slice + ?Sized,
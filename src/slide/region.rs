@@ -0,0 +1,197 @@
+use crate::{
+    bounds::SliceRange,
+    slice::{Slice, get},
+    slide::{Slide, SlideMut},
+};
+
+/// One of the regions of a slide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// The entire source.
+    Source,
+    /// The region before the cursor.
+    Consumed,
+    /// The region at or after the cursor.
+    Remaining,
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Returns the region of this slide specified by `which`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn region_of(
+        &self,
+        which: Region,
+    ) -> &'a S {
+        match which {
+            Region::Source => self.source(),
+            Region::Consumed => self.consumed(),
+            Region::Remaining => self.remaining(),
+        }
+    }
+
+    /// Returns the range that the region of this slide specified by `which` occupies
+    /// within the source.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn range_of(
+        &self,
+        which: Region,
+    ) -> SliceRange {
+        let offset = self.offset();
+        let len = self.source_len();
+
+        match which {
+            Region::Source => SliceRange::up_to(len),
+            Region::Consumed => SliceRange::up_to(offset),
+            // SAFETY: The cursor never lies past the end of the source.
+            Region::Remaining => unsafe { SliceRange::new_unchecked(offset, len) },
+        }
+    }
+
+    /// Returns the range of the entire source, `0..len`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn source_range(&self) -> SliceRange {
+        self.range_of(Region::Source)
+    }
+
+    /// Returns the range of the consumed region within the source, `0..offset`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_range(&self) -> SliceRange {
+        self.range_of(Region::Consumed)
+    }
+
+    /// Returns the range of the remaining region within the source, `offset..len`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_range(&self) -> SliceRange {
+        self.range_of(Region::Remaining)
+    }
+
+    /// Returns the subslice at `range` within the region of this slide specified by `which`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(subslice)` if `range` lies within the region, and both of its endpoints
+    ///   lie on valid split boundaries for `S`.
+    ///
+    /// - `None` otherwise.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn region(
+        &self,
+        which: Region,
+        range: SliceRange,
+    ) -> Option<&'a S> {
+        get(self.region_of(which), range)
+    }
+}
+
+impl<'a, T> Slide<'a, [T]>
+where
+    T: PartialEq,
+{
+    /// Returns whether the remaining region is equal to the subslice at `range` within
+    /// the source.
+    ///
+    /// If `range` does not lie within the source, this returns `false`.
+    #[inline]
+    #[must_use]
+    pub fn remaining_eq_source_range(
+        &self,
+        range: SliceRange,
+    ) -> bool {
+        self.region(Region::Source, range) == Some(self.remaining())
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Returns the range that the region of this slide specified by `which` occupies
+    /// within the source.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn range_of(
+        &self,
+        which: Region,
+    ) -> SliceRange {
+        self.as_slide().range_of(which)
+    }
+
+    /// Returns the range of the entire source, `0..len`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn source_range(&self) -> SliceRange {
+        self.range_of(Region::Source)
+    }
+
+    /// Returns the range of the consumed region within the source, `0..offset`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_range(&self) -> SliceRange {
+        self.range_of(Region::Consumed)
+    }
+
+    /// Returns the range of the remaining region within the source, `offset..len`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_range(&self) -> SliceRange {
+        self.range_of(Region::Remaining)
+    }
+}
+
+/// Captures the range of the remaining region, `offset..len`.
+///
+/// See [`Slide::consumed_range`] and [`Slide::source_range`] for the other regions.
+impl<'a, S> From<Slide<'a, S>> for SliceRange
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn from(slide: Slide<'a, S>) -> Self {
+        slide.remaining_range()
+    }
+}
+
+/// Captures the range of the remaining region, `offset..len`.
+///
+/// See [`Slide::consumed_range`] and [`Slide::source_range`] for the other regions.
+impl<'a, S> From<&Slide<'a, S>> for SliceRange
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn from(slide: &Slide<'a, S>) -> Self {
+        slide.remaining_range()
+    }
+}
+
+/// Captures the range of the remaining region, `offset..len`.
+///
+/// See [`SlideMut::consumed_range`] and [`SlideMut::source_range`] for the other regions.
+impl<'a, S> From<&SlideMut<'a, S>> for SliceRange
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn from(slide: &SlideMut<'a, S>) -> Self {
+        slide.remaining_range()
+    }
+}
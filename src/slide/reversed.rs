@@ -0,0 +1,606 @@
+use core::fmt;
+
+use crate::{
+    slice::{Slice, SplitError},
+    slide::{Slide, SlideMut},
+};
+
+/// A reversed view of a borrowed [`Slide`], where moving forward means moving toward the
+/// start of the source.
+///
+/// Within this view the remaining region is everything *before* the cursor, and the
+/// consumed region is everything *after* it. Offsets are measured from the end of the
+/// source. Every movement is applied directly to the underlying slide, so dropping this
+/// view leaves the cursor wherever it was moved to.
+///
+/// See [`Slide::reversed`].
+pub struct Reversed<'s, 'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're viewing.
+    slide: &'s mut Slide<'a, S>,
+}
+
+impl<'s, 'a, S> Reversed<'s, 'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Returns the underlying slide.
+    #[inline(always)]
+    #[must_use]
+    pub const fn slide(&self) -> Slide<'a, S> {
+        *self.slide
+    }
+
+    /// Returns the offset of the cursor, measured from the end of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.slide.remaining_len()
+    }
+
+    /// Returns the length of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_len(&self) -> usize {
+        self.slide.source_len()
+    }
+
+    /// Returns the length of the consumed region, which lies after the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed_len(&self) -> usize {
+        self.slide.remaining_len()
+    }
+
+    /// Returns the length of the remaining region, which lies before the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining_len(&self) -> usize {
+        self.slide.consumed_len()
+    }
+
+    /// Returns whether the cursor is at the end of the source, which is the start of
+    /// this view.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_start(&self) -> bool {
+        self.slide.is_at_end()
+    }
+
+    /// Returns whether the cursor is at the start of the source, which is the end of
+    /// this view.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_end(&self) -> bool {
+        self.slide.is_at_start()
+    }
+
+    /// Returns the consumed region, which is everything after the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed(&self) -> &'a S {
+        self.slide.remaining()
+    }
+
+    /// Returns the remaining region, which is everything before the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining(&self) -> &'a S {
+        self.slide.consumed()
+    }
+
+    /// Attempt to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    ///
+    /// This is equivalent to [`Slide::try_peek_back`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek(
+        &self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        self.slide.try_peek_back(amount)
+    }
+
+    /// Peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    ///
+    /// This is equivalent to [`Slide::peek_back`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn peek(
+        &self,
+        amount: usize,
+    ) -> &'a S {
+        self.slide.peek_back(amount)
+    }
+
+    /// Peek ahead of the cursor, toward the start of the source, by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`Slide::peek_back_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn peek_unchecked(
+        &self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.peek_back_unchecked(amount) }
+    }
+
+    /// Attempt to advance the cursor toward the start of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`Slide::try_rewind`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(advanced)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to advance the cursor toward the start of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_advance(
+        &mut self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        self.slide.try_rewind(amount)
+    }
+
+    /// Advance the cursor toward the start of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`Slide::rewind`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to advance the cursor toward the start of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn advance(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        self.slide.rewind(amount)
+    }
+
+    /// Advance the cursor toward the start of the source by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`Slide::rewind_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to advance the cursor toward the start of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.rewind_unchecked(amount) }
+    }
+
+    /// Attempt to rewind the cursor toward the end of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`Slide::try_advance`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(rewound)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to rewind the cursor toward the end of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_rewind(
+        &mut self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        self.slide.try_advance(amount)
+    }
+
+    /// Rewind the cursor toward the end of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`Slide::advance`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to rewind the cursor toward the end of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn rewind(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        self.slide.advance(amount)
+    }
+
+    /// Rewind the cursor toward the end of the source by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`Slide::advance_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to rewind the cursor toward the end of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn rewind_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.advance_unchecked(amount) }
+    }
+}
+
+impl<'s, 'a, S> fmt::Debug for Reversed<'s, 'a, S>
+where
+    S: Slice + fmt::Debug + ?Sized,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("Reversed")
+            .field("slide", &self.slide)
+            .finish()
+    }
+}
+
+/// A reversed view of a borrowed [`SlideMut`], where moving forward means moving toward
+/// the start of the source.
+///
+/// This behaves exactly like [`Reversed`], but allows for mutating the source.
+///
+/// See [`SlideMut::reversed`].
+pub struct ReversedMut<'s, 'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're viewing.
+    slide: &'s mut SlideMut<'a, S>,
+}
+
+impl<'s, 'a, S> ReversedMut<'s, 'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Returns the underlying slide.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slide(&self) -> Slide<'_, S> {
+        self.slide.as_slide()
+    }
+
+    /// Returns the offset of the cursor, measured from the end of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.slide.remaining_len()
+    }
+
+    /// Returns the length of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_len(&self) -> usize {
+        self.slide.source_len()
+    }
+
+    /// Returns the length of the consumed region, which lies after the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed_len(&self) -> usize {
+        self.slide.remaining_len()
+    }
+
+    /// Returns the length of the remaining region, which lies before the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining_len(&self) -> usize {
+        self.slide.consumed_len()
+    }
+
+    /// Returns whether the cursor is at the end of the source, which is the start of
+    /// this view.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_start(&self) -> bool {
+        self.slide.is_at_end()
+    }
+
+    /// Returns whether the cursor is at the start of the source, which is the end of
+    /// this view.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_end(&self) -> bool {
+        self.slide.is_at_start()
+    }
+
+    /// Returns the consumed region, which is everything after the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed(&self) -> &S {
+        self.slide.remaining()
+    }
+
+    /// Returns the remaining region, which is everything before the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining(&self) -> &S {
+        self.slide.consumed()
+    }
+
+    /// Returns the consumed region mutably, which is everything after the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed_mut(&mut self) -> &mut S {
+        self.slide.remaining_mut()
+    }
+
+    /// Returns the remaining region mutably, which is everything before the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining_mut(&mut self) -> &mut S {
+        self.slide.consumed_mut()
+    }
+
+    /// Attempt to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::try_peek_back`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek(
+        &self,
+        amount: usize,
+    ) -> Result<&S, SplitError<S>> {
+        self.slide.try_peek_back(amount)
+    }
+
+    /// Peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::peek_back`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn peek(
+        &self,
+        amount: usize,
+    ) -> &S {
+        self.slide.peek_back(amount)
+    }
+
+    /// Peek ahead of the cursor, toward the start of the source, by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`SlideMut::peek_back_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn peek_unchecked(
+        &self,
+        amount: usize,
+    ) -> &S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.peek_back_unchecked(amount) }
+    }
+
+    /// Attempt to mutably peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::try_peek_back_mut`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to mutably peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_mut(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        self.slide.try_peek_back_mut(amount)
+    }
+
+    /// Mutably peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::peek_back_mut`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to mutably peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn peek_mut(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        self.slide.peek_back_mut(amount)
+    }
+
+    /// Mutably peek ahead of the cursor, toward the start of the source, by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`SlideMut::peek_back_mut_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to mutably peek ahead of the cursor, toward the start of the source, by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn peek_mut_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.peek_back_mut_unchecked(amount) }
+    }
+
+    /// Attempt to advance the cursor toward the start of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::try_rewind`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(advanced)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to advance the cursor toward the start of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_advance(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        self.slide.try_rewind(amount)
+    }
+
+    /// Advance the cursor toward the start of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::rewind`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to advance the cursor toward the start of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn advance(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        self.slide.rewind(amount)
+    }
+
+    /// Advance the cursor toward the start of the source by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`SlideMut::rewind_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to advance the cursor toward the start of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.rewind_unchecked(amount) }
+    }
+
+    /// Attempt to rewind the cursor toward the end of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::try_advance`] on the underlying slide.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(rewound)` upon success.
+    ///
+    /// - `Err(error)` if it is invalid to rewind the cursor toward the end of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const fn try_rewind(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        self.slide.try_advance(amount)
+    }
+
+    /// Rewind the cursor toward the end of the source by `amount` elements.
+    ///
+    /// This is equivalent to [`SlideMut::advance`] on the underlying slide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to rewind the cursor toward the end of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub fn rewind(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        self.slide.advance(amount)
+    }
+
+    /// Rewind the cursor toward the end of the source by `amount` elements without any checks.
+    ///
+    /// This is equivalent to [`SlideMut::advance_unchecked`] on the underlying slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to rewind the cursor toward the end of the source by `amount` elements.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn rewind_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The caller ensures that this is valid.
+        unsafe { self.slide.advance_unchecked(amount) }
+    }
+}
+
+impl<'s, 'a, S> fmt::Debug for ReversedMut<'s, 'a, S>
+where
+    S: Slice + fmt::Debug + ?Sized,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("ReversedMut")
+            .field("slide", &self.slide)
+            .finish()
+    }
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Returns a [`Reversed`] view of this slide, where moving forward means moving
+    /// toward the start of the source.
+    ///
+    /// This is useful for algorithms that process the source from right to left,
+    /// without needing to pass a [`Direction`](crate::slide::Direction) around.
+    #[inline(always)]
+    pub const fn reversed(&mut self) -> Reversed<'_, 'a, S> {
+        Reversed { slide: self }
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Returns a [`ReversedMut`] view of this slide, where moving forward means moving
+    /// toward the start of the source.
+    ///
+    /// This is useful for algorithms that process the source from right to left,
+    /// without needing to pass a [`Direction`](crate::slide::Direction) around.
+    #[inline(always)]
+    pub const fn reversed(&mut self) -> ReversedMut<'_, 'a, S> {
+        ReversedMut { slide: self }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    memchr::{memchr, memchr_any},
+    slide::{Slide, SlideMut},
+};
+
+impl<'a> Slide<'a, [u8]> {
+    /// Advance the cursor to the first remaining occurrence of `needle`, leaving the
+    /// cursor *on* the match rather than past it.
+    ///
+    /// This is a faster equivalent to [`Slide::find`] for a single byte.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub const fn find_byte(
+        &mut self,
+        needle: u8,
+    ) -> Option<usize> {
+        let found = memchr(needle, self.remaining());
+
+        self.skip_to(found);
+
+        found
+    }
+
+    /// Advance the cursor to the first remaining byte that is any of `needles`, leaving
+    /// the cursor *on* the match rather than past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub const fn find_any_byte(
+        &mut self,
+        needles: &[u8],
+    ) -> Option<usize> {
+        let found = memchr_any(needles, self.remaining());
+
+        self.skip_to(found);
+
+        found
+    }
+
+    /// Advance the cursor by `found`, or to the end if it is `None`.
+    #[inline(always)]
+    const fn skip_to(
+        &mut self,
+        found: Option<usize>,
+    ) {
+        let amount = match found {
+            Some(amount) => amount,
+            None => self.remaining_len(),
+        };
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(amount) };
+    }
+}
+
+impl<'a> SlideMut<'a, [u8]> {
+    /// Advance the cursor to the first remaining occurrence of `needle`, leaving the
+    /// cursor *on* the match rather than past it.
+    ///
+    /// This is a faster equivalent to [`SlideMut::find`] for a single byte.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub const fn find_byte(
+        &mut self,
+        needle: u8,
+    ) -> Option<usize> {
+        let found = memchr(needle, self.remaining());
+
+        self.skip_to(found);
+
+        found
+    }
+
+    /// Advance the cursor to the first remaining byte that is any of `needles`, leaving
+    /// the cursor *on* the match rather than past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub const fn find_any_byte(
+        &mut self,
+        needles: &[u8],
+    ) -> Option<usize> {
+        let found = memchr_any(needles, self.remaining());
+
+        self.skip_to(found);
+
+        found
+    }
+
+    /// Advance the cursor by `found`, or to the end if it is `None`.
+    #[inline(always)]
+    const fn skip_to(
+        &mut self,
+        found: Option<usize>,
+    ) {
+        let amount = match found {
+            Some(amount) => amount,
+            None => self.remaining_len(),
+        };
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(amount) };
+    }
+}
@@ -0,0 +1,2029 @@
+use core::{fmt, marker::PhantomData, ptr::NonNull, slice::SliceIndex};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    macros::assert_unchecked,
+    mem::NoDrop,
+    slice::{Slice, SplitError, len},
+    slide::{
+        Slide,
+        location::Location,
+        raw::{Movement, RawSlide},
+    },
+};
+
+/// A cursor that slides across a mutable slice.
+///
+/// A [`SlideMut`] splits its source into two regions at its cursor:
+///
+/// - The *consumed* region, which lies before the cursor.
+///
+/// - The *remaining* region, which lies at or after the cursor.
+///
+/// The cursor *always* lies on a valid split boundary for `S`. See the
+/// documentation for [`Slice::validate_split_at`] as implemented for `S`
+/// for details on what is considered a valid split boundary.
+#[repr(transparent)]
+pub struct SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// The underlying raw slide.
+    ///
+    /// # Safety
+    ///
+    /// The raw slide must be derived from a `&'a mut S`.
+    raw: RawSlide<S>,
+    /// Marker for the mutable borrow of the source.
+    _marker: PhantomData<&'a mut S>,
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Create a new [`SlideMut`] over `slice` from a raw slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `raw` is derived from a `&'a mut S`.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const unsafe fn from_raw(raw: RawSlide<S>) -> SlideMut<'a, S> {
+        SlideMut {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the underlying raw slide.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn into_raw(self) -> RawSlide<S> {
+        self.raw
+    }
+
+    /// Create a new [`SlideMut`] over `slice` with the cursor at the start.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn new(slice: &'a mut S) -> SlideMut<'a, S> {
+        // SAFETY: It is always valid to split a slice at `0`.
+        unsafe { SlideMut::with_offset_unchecked(slice, 0) }
+    }
+
+    /// Attempt to create a new [`SlideMut`] over `slice` with the cursor at `offset`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slide)` upon success.
+    ///
+    /// - `Err(error)` if `offset` is not a valid split boundary for `slice`. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_with_offset(
+        slice: &'a mut S,
+        offset: usize,
+    ) -> Result<SlideMut<'a, S>, SplitError<S>> {
+        match NoDrop::new(RawSlide::from_mut(slice, offset)).transpose() {
+            // SAFETY: `raw` is derived from a `&'a mut S`.
+            Ok(raw) => Ok(unsafe { SlideMut::from_raw(raw.into_inner()) }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Create a new [`SlideMut`] over `slice` with the cursor at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is not a valid split boundary for `slice`. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn with_offset(
+        slice: &'a mut S,
+        offset: usize,
+    ) -> SlideMut<'a, S> {
+        match RawSlide::from_mut(slice, offset) {
+            // SAFETY: `raw` is derived from a `&'a mut S`.
+            Ok(raw) => unsafe { SlideMut::from_raw(raw) },
+            Err(error) => {
+                SlideMut::new(slice)
+                    .raw
+                    .context_panic(Movement::WithOffset, offset, error)
+            }
+        }
+    }
+
+    /// Create a new [`SlideMut`] over `slice` with the cursor at `offset` without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `offset` is a valid split boundary for `slice`. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    /// Despite this method not doing any checks on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn with_offset_unchecked(
+        slice: &'a mut S,
+        offset: usize,
+    ) -> SlideMut<'a, S> {
+        match NoDrop::new(SlideMut::try_with_offset(slice, offset)).transpose() {
+            Ok(slide) => slide.into_inner(),
+            // SAFETY: The caller ensures that `offset` is a valid split boundary.
+            Err(error) => unsafe { error.into_inner().panic_unchecked() },
+        }
+    }
+
+    /// Create a new [`SlideMut`] over the slice pointed to by `source` with the cursor at `offset`.
+    ///
+    /// This is primarily intended for FFI, where one may have a pointer and a length rather
+    /// than a reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - `source` points to a single, valid *allocated object* that is properly initialized
+    ///   for `S`, with the length carried by `source` being correct.
+    ///
+    /// - `source` is valid to be read from and written to for `'a`, and that `'a` does not outlive the
+    ///   allocation.
+    ///
+    /// - The memory pointed to by `source` is not accessed through any other pointer for `'a`,
+    ///   as it is semantically borrowed as a `&'a mut S`.
+    ///
+    /// - `offset` is a valid split boundary for the slice pointed to by `source`. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    /// Despite this method not checking `offset` on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn from_nonnull(
+        source: NonNull<S>,
+        offset: usize,
+    ) -> SlideMut<'a, S> {
+        // SAFETY: The caller ensures that `source` is a valid `S`.
+        match NoDrop::new(unsafe { RawSlide::new(source, offset) }).transpose() {
+            // SAFETY: The caller ensures that `source` is semantically a `&'a mut S`.
+            Ok(raw) => unsafe { SlideMut::from_raw(raw.into_inner()) },
+            // SAFETY: The caller ensures that `offset` is a valid split boundary.
+            Err(error) => unsafe { error.into_inner().panic_unchecked() },
+        }
+    }
+
+    /// Borrow this [`SlideMut`] as a [`Slide`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slide(&self) -> Slide<'_, S> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, which we borrow immutably.
+        unsafe { Slide::from_raw(self.raw) }
+    }
+
+    /// Convert this [`SlideMut`] into a [`Slide`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_slide(self) -> Slide<'a, S> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, which we give up.
+        unsafe { Slide::from_raw(self.raw) }
+    }
+
+    /// Reborrow this [`SlideMut`] with a shorter lifetime.
+    ///
+    /// Moving the cursor of the returned slide moves the cursor of the returned slide
+    /// *only*. The cursor of `self` is left untouched.
+    #[inline(always)]
+    #[must_use]
+    pub const fn reborrow(&mut self) -> SlideMut<'_, S> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, which we borrow mutably.
+        unsafe { SlideMut::from_raw(self.raw) }
+    }
+
+    /// Returns whether [`SlideMut`]s over `S` track their cursor with indices rather than pointers.
+    ///
+    /// Slides are index-based when:
+    ///
+    /// - `S::Elem` is a zero sized type.
+    ///
+    /// - Debug assertions are enabled, in order to catch *undefined behavior*.
+    ///
+    /// - The `force_index` feature is enabled.
+    ///
+    /// Otherwise, slides are pointer-based.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_index_based() -> bool {
+        Location::<S>::INDEX_BASED
+    }
+
+    /// Tell the compiler that there are at least `amount` remaining elements.
+    ///
+    /// This does nothing on its own, but can be used to inject an invariant that is
+    /// known to hold, in order to get better codegen for subsequent operations, such as
+    /// a batch of unchecked peeks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `amount` remaining elements.
+    ///
+    /// Despite this method not doing any checks on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    #[inline(always)]
+    #[track_caller]
+    pub const unsafe fn assume_remaining_at_least(
+        &self,
+        amount: usize,
+    ) {
+        self.raw.compiler_hints();
+
+        // SAFETY: The caller ensures that there are at least `amount` remaining elements.
+        unsafe {
+            assert_unchecked!(
+                self.raw.remaining_len() >= amount,
+                "`remaining_len < amount`"
+            )
+        };
+    }
+
+    /// Returns the offset of the cursor from the start of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.raw.consumed_len()
+    }
+
+    /// Returns the index of the cursor within the source.
+    ///
+    /// This is an alias for [`Self::offset`], for translating between indices relative
+    /// to the cursor and indices into the source: `source_index() + i` is the source
+    /// index of the remaining element at `i`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_index(&self) -> usize {
+        self.offset()
+    }
+
+    /// Returns the length of the entire source.
+    ///
+    /// This shrinks if the end of this slide is moved, see [`SlideMut::source`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_len(&self) -> usize {
+        self.raw.entire_len()
+    }
+
+    /// Returns the length of the consumed region.
+    ///
+    /// This is always equal to the offset of the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed_len(&self) -> usize {
+        self.raw.consumed_len()
+    }
+
+    /// Returns the length of the remaining region.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining_len(&self) -> usize {
+        self.raw.remaining_len()
+    }
+
+    /// Returns whether the cursor is at the start of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_start(&self) -> bool {
+        self.raw.consumed_len() == 0
+    }
+
+    /// Returns whether the cursor is at the end of the source.
+    ///
+    /// This is an alias for [`Self::is_exhausted`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_end(&self) -> bool {
+        self.is_exhausted()
+    }
+
+    /// Returns whether there are no remaining elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.raw.remaining_len() == 0
+    }
+
+    /// Returns whether the cursor is at the start of the source.
+    ///
+    /// This is an alias for [`Self::is_at_start`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn at_start(&self) -> bool {
+        self.is_at_start()
+    }
+
+    /// Returns whether the cursor is at the end of the source.
+    ///
+    /// This is an alias for [`Self::is_exhausted`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn at_end(&self) -> bool {
+        self.is_exhausted()
+    }
+
+    /// Returns the entire source slice.
+    ///
+    /// The source ends at the end of this slide. This is the end of the slice this slide
+    /// was created from, unless the end has since been moved toward the start by
+    /// [`SlideMut::dedup_remaining`] or [`SlideMut::retain_remaining`]. Anything past the
+    /// end can no longer be reached through this slide.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn source(&self) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.entire_ref() }
+    }
+
+    /// Returns the entire source slice, mutably.
+    ///
+    /// This shrinks if the end of this slide is moved, see [`SlideMut::source`].
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn source_mut(&mut self) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.entire_mut() }
+    }
+
+    /// Returns the entire source slice, taking ownership of `self`.
+    ///
+    /// This shrinks if the end of this slide is moved, see [`SlideMut::source`].
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_source(mut self) -> &'a mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.entire_mut() }
+    }
+
+    /// Returns the consumed region, the region before the cursor.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed(&self) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.consumed_ref() }
+    }
+
+    /// Returns the consumed region, mutably.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_mut(&mut self) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.consumed_mut() }
+    }
+
+    /// Returns the consumed region, taking ownership of `self`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_consumed(mut self) -> &'a mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.consumed_mut() }
+    }
+
+    /// Returns the remaining region, the region at or after the cursor.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining(&self) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.remaining_ref() }
+    }
+
+    /// Returns the remaining region, mutably.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_mut(&mut self) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.remaining_mut() }
+    }
+
+    /// Returns the remaining region, taking ownership of `self`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_remaining(mut self) -> &'a mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.remaining_mut() }
+    }
+
+    /// Returns a new slide over the remaining region, with its cursor at the start.
+    ///
+    /// The returned slide borrows `self`, leaving this slide untouched.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_slide(&self) -> Slide<'_, S> {
+        Slide::new(self.remaining())
+    }
+
+    /// Returns a new mutable slide over the remaining region, with its cursor at the start.
+    ///
+    /// The returned slide mutably borrows `self`, leaving this slide's cursor untouched.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_slide_mut(&mut self) -> SlideMut<'_, S> {
+        SlideMut::new(self.remaining_mut())
+    }
+
+    /// Returns a new slide over the consumed region, with its cursor at the start.
+    ///
+    /// The returned slide borrows `self`, leaving this slide untouched.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_slide(&self) -> Slide<'_, S> {
+        Slide::new(self.consumed())
+    }
+
+    /// Returns a new mutable slide over the consumed region, with its cursor at the start.
+    ///
+    /// The returned slide mutably borrows `self`, leaving this slide's cursor untouched.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_slide_mut(&mut self) -> SlideMut<'_, S> {
+        SlideMut::new(self.consumed_mut())
+    }
+
+    /// Returns a new mutable slide over the consumed region, with its cursor at the start,
+    /// taking ownership of `self`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_consumed_slide(self) -> SlideMut<'a, S> {
+        SlideMut::new(self.into_consumed())
+    }
+
+    /// Returns the source split at the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed, remaining)`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn split(&self) -> (&S, &S) {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.split_ref() }
+    }
+
+    /// Returns the source split at the cursor, mutably.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed, remaining)`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_mut(&mut self) -> (&mut S, &mut S) {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.split_mut() }
+    }
+
+    /// Returns the source split at the cursor, taking ownership of `self`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed, remaining)`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_split(mut self) -> (&'a mut S, &'a mut S) {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        unsafe { self.raw.split_mut() }
+    }
+
+    /// Returns the source split at the cursor, as a pair of mutable slides.
+    ///
+    /// The slide over the consumed region has its cursor at the end, and the slide over
+    /// the remaining region has its cursor at the start.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed, remaining)`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_slides(&mut self) -> (SlideMut<'_, S>, SlideMut<'_, S>) {
+        let (consumed, remaining) = self.split_mut();
+        let end = len(consumed);
+
+        // SAFETY: It is always valid to split a slice at its end.
+        let consumed = unsafe { SlideMut::with_offset_unchecked(consumed, end) };
+
+        (consumed, SlideMut::new(remaining))
+    }
+
+    /// Returns the source split at the cursor, as a pair of mutable slides, taking
+    /// ownership of `self`.
+    ///
+    /// The slide over the consumed region has its cursor at the end, and the slide over
+    /// the remaining region has its cursor at the start.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed, remaining)`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_sub_slides(self) -> (SlideMut<'a, S>, SlideMut<'a, S>) {
+        let (consumed, remaining) = self.into_split();
+        let end = len(consumed);
+
+        // SAFETY: It is always valid to split a slice at its end.
+        let consumed = unsafe { SlideMut::with_offset_unchecked(consumed, end) };
+
+        (consumed, SlideMut::new(remaining))
+    }
+}
+
+impl<'a, S> SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Attempt to peek ahead of the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `amount` elements of
+    ///   the remaining region.
+    ///
+    /// - `Err(error)` if it is invalid to peek ahead by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek(
+        &self,
+        amount: usize,
+    ) -> Result<&S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match NoDrop::new(unsafe { self.raw.try_peek_ahead(amount) }).transpose() {
+            // SAFETY: The peeked region is borrowed from `self`.
+            Ok(peeked) => Ok(unsafe { peeked.into_inner().as_ref() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Peek ahead of the cursor by `amount` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek ahead by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek(
+        &self,
+        amount: usize,
+    ) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match unsafe { self.raw.try_peek_ahead(amount) } {
+            // SAFETY: The peeked region is borrowed from `self`.
+            Ok(peeked) => unsafe { peeked.as_ref() },
+            Err(error) => self.raw.context_panic(Movement::Peek, amount, error),
+        }
+    }
+
+    /// Peek ahead of the cursor by `amount` elements without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek ahead by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_unchecked(
+        &self,
+        amount: usize,
+    ) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, the peeked region
+        //         is borrowed from `self`, and the caller ensures that it is valid
+        //         to peek ahead by `amount`.
+        unsafe { self.raw.peek_ahead_unchecked(amount).as_ref() }
+    }
+
+    /// Attempt to mutably peek ahead of the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `amount` elements of
+    ///   the remaining region.
+    ///
+    /// - `Err(error)` if it is invalid to peek ahead by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_mut(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match NoDrop::new(unsafe { self.raw.try_peek_ahead(amount) }).transpose() {
+            // SAFETY: The peeked region is mutably borrowed from `self`.
+            Ok(peeked) => Ok(unsafe { peeked.into_inner().as_mut() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Mutably peek ahead of the cursor by `amount` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek ahead by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_mut(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match unsafe { self.raw.try_peek_ahead(amount) } {
+            // SAFETY: The peeked region is mutably borrowed from `self`.
+            Ok(mut peeked) => unsafe { peeked.as_mut() },
+            Err(error) => self.raw.context_panic(Movement::Peek, amount, error),
+        }
+    }
+
+    /// Mutably peek ahead of the cursor by `amount` elements without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek ahead by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_mut_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, the peeked region
+        //         is mutably borrowed from `self`, and the caller ensures that it
+        //         is valid to peek ahead by `amount`.
+        unsafe { self.raw.peek_ahead_unchecked(amount).as_mut() }
+    }
+
+    /// Attempt to peek behind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the last `amount` elements of
+    ///   the consumed region.
+    ///
+    /// - `Err(error)` if it is invalid to peek behind by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_back(
+        &self,
+        amount: usize,
+    ) -> Result<&S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match NoDrop::new(unsafe { self.raw.try_peek_behind(amount) }).transpose() {
+            // SAFETY: The peeked region is borrowed from `self`.
+            Ok(peeked) => Ok(unsafe { peeked.into_inner().as_ref() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Peek behind the cursor by `amount` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek behind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_back(
+        &self,
+        amount: usize,
+    ) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match unsafe { self.raw.try_peek_behind(amount) } {
+            // SAFETY: The peeked region is borrowed from `self`.
+            Ok(peeked) => unsafe { peeked.as_ref() },
+            Err(error) => self.raw.context_panic(Movement::PeekBack, amount, error),
+        }
+    }
+
+    /// Peek behind the cursor by `amount` elements without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek behind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_back_unchecked(
+        &self,
+        amount: usize,
+    ) -> &S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, the peeked region
+        //         is borrowed from `self`, and the caller ensures that it is valid
+        //         to peek behind by `amount`.
+        unsafe { self.raw.peek_behind_unchecked(amount).as_ref() }
+    }
+
+    /// Attempt to mutably peek behind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the last `amount` elements of
+    ///   the consumed region.
+    ///
+    /// - `Err(error)` if it is invalid to peek behind by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_back_mut(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match NoDrop::new(unsafe { self.raw.try_peek_behind(amount) }).transpose() {
+            // SAFETY: The peeked region is mutably borrowed from `self`.
+            Ok(peeked) => Ok(unsafe { peeked.into_inner().as_mut() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Mutably peek behind the cursor by `amount` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek behind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_back_mut(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match unsafe { self.raw.try_peek_behind(amount) } {
+            // SAFETY: The peeked region is mutably borrowed from `self`.
+            Ok(mut peeked) => unsafe { peeked.as_mut() },
+            Err(error) => self.raw.context_panic(Movement::PeekBack, amount, error),
+        }
+    }
+
+    /// Mutably peek behind the cursor by `amount` elements without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek behind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_back_mut_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, the peeked region
+        //         is mutably borrowed from `self`, and the caller ensures that it
+        //         is valid to peek behind by `amount`.
+        unsafe { self.raw.peek_behind_unchecked(amount).as_mut() }
+    }
+
+    /// Attempt to advance the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(advanced)` upon success, where `advanced` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if it is invalid to advance by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub const fn try_advance(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match NoDrop::new(unsafe { self.raw.try_advance(amount) }).transpose() {
+            // SAFETY: The advanced region is mutably borrowed from `self`.
+            Ok(advanced) => Ok(unsafe { advanced.into_inner().as_mut() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Advance the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to advance by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn advance(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match unsafe { self.raw.try_advance(amount) } {
+            // SAFETY: The advanced region is mutably borrowed from `self`.
+            Ok(mut advanced) => unsafe { advanced.as_mut() },
+            Err(error) => self.raw.context_panic(Movement::Advance, amount, error),
+        }
+    }
+
+    /// Advance the cursor by `amount` elements without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to advance by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, the advanced region
+        //         is mutably borrowed from `self`, and the caller ensures that it
+        //         is valid to advance by `amount`.
+        unsafe { self.raw.advance_unchecked(amount).as_mut() }
+    }
+
+    /// Attempt to rewind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(rewound)` upon success, where `rewound` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if it is invalid to rewind by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub const fn try_rewind(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match NoDrop::new(unsafe { self.raw.try_rewind(amount) }).transpose() {
+            // SAFETY: The rewound region is mutably borrowed from `self`.
+            Ok(rewound) => Ok(unsafe { rewound.into_inner().as_mut() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Rewind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to rewind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn rewind(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`.
+        match unsafe { self.raw.try_rewind(amount) } {
+            // SAFETY: The rewound region is mutably borrowed from `self`.
+            Ok(mut rewound) => unsafe { rewound.as_mut() },
+            Err(error) => self.raw.context_panic(Movement::Rewind, amount, error),
+        }
+    }
+
+    /// Rewind the cursor by `amount` elements without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to rewind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn rewind_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &mut S {
+        // SAFETY: The raw slide is derived from a `&'a mut S`, the rewound region
+        //         is mutably borrowed from `self`, and the caller ensures that it
+        //         is valid to rewind by `amount`.
+        unsafe { self.raw.rewind_unchecked(amount).as_mut() }
+    }
+
+    /// Advance the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(advanced)` upon success, where `advanced` is the region the cursor moved over.
+    ///
+    /// - `None` if `offset` is before the cursor, or if it is invalid to advance to
+    ///   `offset`. See the documentation for [`Slice::validate_split_at`] as implemented
+    ///   for `S` for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn advance_to_checked(
+        &mut self,
+        offset: usize,
+    ) -> Option<&mut S> {
+        let Some(amount) = offset.checked_sub(self.offset()) else {
+            return None;
+        };
+
+        match NoDrop::new(self.try_advance(amount)).transpose() {
+            Ok(advanced) => Some(advanced.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Advance the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is before the cursor, or if it is invalid to advance to `offset`.
+    /// See the documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn advance_to(
+        &mut self,
+        offset: usize,
+    ) -> &mut S {
+        let current = self.offset();
+
+        match offset.checked_sub(current) {
+            Some(amount) => self.advance(amount),
+            None => panic!("advance_to({offset}) but the cursor is at offset {current}"),
+        }
+    }
+
+    /// Rewind the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(rewound)` upon success, where `rewound` is the region the cursor moved over.
+    ///
+    /// - `None` if `offset` is after the cursor, or if it is invalid to rewind to `offset`.
+    ///   See the documentation for [`Slice::validate_split_at`] as implemented for `S`
+    ///   for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn rewind_to_checked(
+        &mut self,
+        offset: usize,
+    ) -> Option<&mut S> {
+        let Some(amount) = self.offset().checked_sub(offset) else {
+            return None;
+        };
+
+        match NoDrop::new(self.try_rewind(amount)).transpose() {
+            Ok(rewound) => Some(rewound.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Rewind the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is after the cursor, or if it is invalid to rewind to `offset`.
+    /// See the documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn rewind_to(
+        &mut self,
+        offset: usize,
+    ) -> &mut S {
+        let current = self.offset();
+
+        match current.checked_sub(offset) {
+            Some(amount) => self.rewind(amount),
+            None => panic!("rewind_to({offset}) but the cursor is at offset {current}"),
+        }
+    }
+}
+
+impl<'a, T> SlideMut<'a, [T]> {
+    /// Create a new [`SlideMut`] over the current contents of `vec`, with the cursor at
+    /// the start.
+    ///
+    /// The slide views the elements of `vec` at the time of creation, not its capacity.
+    /// Since the slide mutably borrows `vec`, nothing can be pushed to it while the slide
+    /// is in use.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_vec(vec: &'a mut Vec<T>) -> SlideMut<'a, [T]> {
+        SlideMut::new(vec.as_mut_slice())
+    }
+
+    /// Returns the next remaining element, if any, without moving the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn peek_one(&self) -> Option<&T> {
+        self.remaining().first()
+    }
+
+    /// Returns the next remaining element mutably, if any, without moving the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn peek_one_mut(&mut self) -> Option<&mut T> {
+        self.remaining_mut().first_mut()
+    }
+
+    /// Returns the last consumed element, if any, without moving the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn peek_one_back(&self) -> Option<&T> {
+        self.consumed().last()
+    }
+
+    /// Returns the last consumed element mutably, if any, without moving the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn peek_one_back_mut(&mut self) -> Option<&mut T> {
+        self.consumed_mut().last_mut()
+    }
+
+    /// Returns the remaining element `index` elements after the cursor, if any, without
+    /// moving the cursor.
+    ///
+    /// A `peek_at(0)` is equivalent to `peek_one()`.
+    #[inline]
+    #[must_use]
+    pub const fn peek_at(
+        &self,
+        index: usize,
+    ) -> Option<&T> {
+        self.as_slide().peek_at(index)
+    }
+
+    /// Returns the remaining element `index` elements after the cursor mutably, if any,
+    /// without moving the cursor.
+    ///
+    /// A `peek_mut_at(0)` is equivalent to `peek_one_mut()`.
+    #[inline]
+    #[must_use]
+    pub const fn peek_mut_at(
+        &mut self,
+        index: usize,
+    ) -> Option<&mut T> {
+        let remaining = self.remaining_mut();
+
+        if index < remaining.len() {
+            Some(&mut remaining[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the consumed element `index` elements before the cursor, if any, without
+    /// moving the cursor.
+    ///
+    /// A `peek_back_at(0)` is equivalent to `peek_one_back()`.
+    #[inline]
+    #[must_use]
+    pub const fn peek_back_at(
+        &self,
+        index: usize,
+    ) -> Option<&T> {
+        self.as_slide().peek_back_at(index)
+    }
+
+    /// Returns the element or subslice of the remaining region at `index`, if any.
+    ///
+    /// Note that `index` is relative to the cursor, *not* to the start of the source. So
+    /// `get(0)` is the first remaining element, regardless of where the cursor lies.
+    #[inline]
+    #[must_use]
+    pub fn get<I>(
+        &self,
+        index: I,
+    ) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.remaining().get(index)
+    }
+
+    /// Returns the element or subslice of the remaining region at `index` mutably, if any.
+    ///
+    /// Note that `index` is relative to the cursor, *not* to the start of the source.
+    #[inline]
+    #[must_use]
+    pub fn get_mut<I>(
+        &mut self,
+        index: I,
+    ) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.remaining_mut().get_mut(index)
+    }
+
+    /// Returns the element or subslice of the consumed region at `index`, if any.
+    ///
+    /// As the consumed region starts at the start of the source, `index` is effectively
+    /// absolute, but only elements before the cursor can be reached.
+    #[inline]
+    #[must_use]
+    pub fn get_consumed<I>(
+        &self,
+        index: I,
+    ) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.consumed().get(index)
+    }
+
+    /// Returns the element or subslice of the consumed region at `index` mutably, if any.
+    ///
+    /// As the consumed region starts at the start of the source, `index` is effectively
+    /// absolute, but only elements before the cursor can be reached.
+    #[inline]
+    #[must_use]
+    pub fn get_consumed_mut<I>(
+        &mut self,
+        index: I,
+    ) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.consumed_mut().get_mut(index)
+    }
+
+    /// Returns the element or subslice of the source at `index`, if any.
+    ///
+    /// Unlike [`SlideMut::get`], `index` is absolute, and is relative to the start of the
+    /// source rather than to the cursor.
+    #[inline]
+    #[must_use]
+    pub fn source_get<I>(
+        &self,
+        index: I,
+    ) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.source().get(index)
+    }
+
+    /// Returns the element or subslice of the source at `index` mutably, if any.
+    ///
+    /// Unlike [`SlideMut::get_mut`], `index` is absolute, and is relative to the start of
+    /// the source rather than to the cursor.
+    #[inline]
+    #[must_use]
+    pub fn source_get_mut<I>(
+        &mut self,
+        index: I,
+    ) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.source_mut().get_mut(index)
+    }
+
+    /// Advance the cursor by `amount` elements, or as far as possible.
+    ///
+    /// This mirrors [`Iterator::advance_by`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the cursor was advanced by exactly `amount` elements.
+    ///
+    /// - `Err(leftover)` if there were less than `amount` remaining elements, where
+    ///   `leftover` is the amount of elements the cursor could not be advanced by.
+    ///
+    ///   The cursor is moved to the end upon failure.
+    #[inline]
+    pub const fn advance_by(
+        &mut self,
+        amount: usize,
+    ) -> Result<(), usize> {
+        let remaining = self.raw.remaining_len();
+
+        if amount <= remaining {
+            // SAFETY: We just checked that there are at least `amount` remaining elements.
+            unsafe { self.advance_unchecked(amount) };
+
+            Ok(())
+        } else {
+            // SAFETY: It is always valid to advance to the end.
+            unsafe { self.advance_unchecked(remaining) };
+
+            // SAFETY: We know that `amount > remaining`.
+            Err(unsafe { amount.unchecked_sub(remaining) })
+        }
+    }
+
+    /// Rewind the cursor by `amount` elements, or as far as possible.
+    ///
+    /// This mirrors [`DoubleEndedIterator::advance_back_by`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the cursor was rewound by exactly `amount` elements.
+    ///
+    /// - `Err(leftover)` if there were less than `amount` consumed elements, where
+    ///   `leftover` is the amount of elements the cursor could not be rewound by.
+    ///
+    ///   The cursor is moved to the start upon failure.
+    #[inline]
+    pub const fn rewind_by(
+        &mut self,
+        amount: usize,
+    ) -> Result<(), usize> {
+        let consumed = self.raw.consumed_len();
+
+        if amount <= consumed {
+            // SAFETY: We just checked that there are at least `amount` consumed elements.
+            unsafe { self.rewind_unchecked(amount) };
+
+            Ok(())
+        } else {
+            // SAFETY: It is always valid to rewind to the start.
+            unsafe { self.rewind_unchecked(consumed) };
+
+            // SAFETY: We know that `amount > consumed`.
+            Err(unsafe { amount.unchecked_sub(consumed) })
+        }
+    }
+
+    /// Write items from `iter` into the remaining region, advancing the cursor past
+    /// each written item.
+    ///
+    /// Items are pulled from `iter` until either it is exhausted or the remaining region
+    /// is full. No more items are pulled than can be written.
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount of items written.
+    #[inline]
+    pub fn write_from_iter<I>(
+        &mut self,
+        iter: I,
+    ) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut written = 0;
+
+        while self.raw.remaining_len() > 0 {
+            let Some(item) = iter.next() else {
+                break;
+            };
+
+            // SAFETY: We just checked that there is at least one remaining element.
+            let slot = unsafe { self.advance_unchecked(1) };
+
+            slot[0] = item;
+            written += 1;
+        }
+
+        written
+    }
+
+    /// Write a clone of `value` into every remaining element, and advance the cursor to
+    /// the end.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region that was filled.
+    #[inline]
+    pub fn fill(
+        &mut self,
+        value: T,
+    ) -> &mut [T]
+    where
+        T: Clone,
+    {
+        self.remaining_mut().fill(value);
+
+        // SAFETY: Advancing by the remaining length always lands on the end.
+        unsafe { self.advance_unchecked(self.raw.remaining_len()) }
+    }
+
+    /// Write the values returned by `f` into every remaining element, in order, and
+    /// advance the cursor to the end.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region that was filled.
+    #[inline]
+    pub fn fill_with<F>(
+        &mut self,
+        f: F,
+    ) -> &mut [T]
+    where
+        F: FnMut() -> T,
+    {
+        self.remaining_mut().fill_with(f);
+
+        // SAFETY: Advancing by the remaining length always lands on the end.
+        unsafe { self.advance_unchecked(self.raw.remaining_len()) }
+    }
+
+    /// Write a clone of `value` into every consumed element, without moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region that was filled.
+    #[inline]
+    pub fn fill_consumed(
+        &mut self,
+        value: T,
+    ) -> &mut [T]
+    where
+        T: Clone,
+    {
+        let consumed = self.consumed_mut();
+
+        consumed.fill(value);
+        consumed
+    }
+
+    /// Copy `src` into the start of the remaining region, and advance the cursor past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` upon success.
+    ///
+    /// - `Err(remaining_len)` if `src` does not fit within the remaining region, in which
+    ///   case nothing is written and the cursor is not moved.
+    #[inline]
+    pub const fn copy_from(
+        &mut self,
+        src: &[T],
+    ) -> Result<(), usize>
+    where
+        T: Copy,
+    {
+        let remaining_len = self.raw.remaining_len();
+
+        if src.len() > remaining_len {
+            return Err(remaining_len);
+        }
+
+        // SAFETY: We just checked that `src` fits within the remaining region, and any
+        //         index is a valid split boundary for `[T]`.
+        let dst = unsafe { self.advance_unchecked(src.len()) };
+
+        dst.copy_from_slice(src);
+
+        Ok(())
+    }
+
+    /// Clone `src` into the start of the remaining region, and advance the cursor past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` upon success.
+    ///
+    /// - `Err(remaining_len)` if `src` does not fit within the remaining region, in which
+    ///   case nothing is written and the cursor is not moved.
+    #[inline]
+    pub fn clone_from(
+        &mut self,
+        src: &[T],
+    ) -> Result<(), usize>
+    where
+        T: Clone,
+    {
+        let remaining_len = self.raw.remaining_len();
+
+        if src.len() > remaining_len {
+            return Err(remaining_len);
+        }
+
+        // SAFETY: We just checked that `src` fits within the remaining region, and any
+        //         index is a valid split boundary for `[T]`.
+        let dst = unsafe { self.advance_unchecked(src.len()) };
+
+        dst.clone_from_slice(src);
+
+        Ok(())
+    }
+
+    /// Advance the cursor for as long as the next remaining element matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn advance_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &mut [T]
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let amount = self
+            .remaining()
+            .iter()
+            .position(|elem| !pred(elem))
+            .unwrap_or(self.raw.remaining_len());
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(amount) }
+    }
+
+    /// Rewind the cursor for as long as the last consumed element matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn rewind_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &mut [T]
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let consumed = self.consumed();
+        let amount = consumed
+            .iter()
+            .rev()
+            .position(|elem| !pred(elem))
+            .unwrap_or(consumed.len());
+
+        // SAFETY: `amount` is never greater than the length of the consumed region.
+        unsafe { self.rewind_unchecked(amount) }
+    }
+
+    /// Advance the cursor to the first remaining element that matches `pred`, leaving the
+    /// cursor *on* the match rather than past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of elements skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub fn find<P>(
+        &mut self,
+        pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let found = self.remaining().iter().position(pred);
+        let amount = found.unwrap_or(self.remaining_len());
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(amount) };
+
+        found
+    }
+
+    /// Rewind the cursor to just after the last consumed element that matches `pred`, so
+    /// that the match is the last element of the consumed region.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of elements skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the start.
+    #[inline]
+    pub fn rfind<P>(
+        &mut self,
+        pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let found = self.consumed().iter().rev().position(pred);
+        let amount = found.unwrap_or(self.consumed_len());
+
+        // SAFETY: `amount` is never greater than the length of the consumed region.
+        unsafe { self.rewind_unchecked(amount) };
+
+        found
+    }
+
+    /// Retain only the elements within the remaining region for which `pred` returns `true`,
+    /// in place.
+    ///
+    /// This behaves like `Vec::retain`, but only affects the remaining region. The retained
+    /// elements keep their relative order, and the end of this slide is moved so that the
+    /// remaining region only contains the retained elements.
+    ///
+    /// The removed elements are not dropped. They are moved past the new end of the
+    /// slide, where they remain in the underlying buffer in an unspecified order.
+    ///
+    /// As the end of this slide is moved, [`SlideMut::source`], [`SlideMut::source_len`],
+    /// [`SlideMut::source_mut`] and [`SlideMut::into_source`] all shrink to match, and
+    /// the removed elements can no longer be reached through this slide.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new length of the remaining region.
+    #[inline]
+    pub fn retain_remaining<P>(
+        &mut self,
+        mut pred: P,
+    ) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let remaining = self.remaining_mut();
+
+        // NOTE: Everything before `write` is retained, and everything in `write..read`
+        //       is to be removed.
+        let mut write = 0;
+
+        for read in 0..remaining.len() {
+            if pred(&remaining[read]) {
+                remaining.swap(read, write);
+                write += 1;
+            }
+        }
+
+        // SAFETY: `write <= len`, and any index is a valid split boundary for `[T]`.
+        unsafe { self.raw.truncate_remaining_unchecked(write) };
+
+        write
+    }
+}
+
+impl<'a> SlideMut<'a, str> {
+    /// Advance the cursor past `prefix` if the remaining region starts with it.
+    ///
+    /// An empty `prefix` always matches, without moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the remaining region started with `prefix`. If not, the cursor
+    /// is not moved.
+    #[inline]
+    pub fn advance_if_str(
+        &mut self,
+        prefix: &str,
+    ) -> bool {
+        if !self.remaining().starts_with(prefix) {
+            return false;
+        }
+
+        // SAFETY: We just checked that the remaining region starts with `prefix`, which
+        //         being a `str` itself, ends on a char boundary.
+        unsafe { self.advance_unchecked(prefix.len()) };
+
+        true
+    }
+
+    /// Advance the cursor for as long as the next remaining [`char`] matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn advance_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &mut str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let remaining = self.remaining();
+        let amount = remaining
+            .char_indices()
+            .find(|&(_, ch)| !pred(ch))
+            .map_or(remaining.len(), |(index, _)| index);
+
+        // SAFETY: `amount` is the start of a `char` within the remaining region, or its end.
+        unsafe { self.advance_unchecked(amount) }
+    }
+
+    /// Rewind the cursor for as long as the last consumed [`char`] matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn rewind_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &mut str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let consumed = self.consumed();
+        let amount = consumed
+            .char_indices()
+            .rev()
+            .find(|&(_, ch)| !pred(ch))
+            .map_or(consumed.len(), |(index, ch)| {
+                consumed.len() - index - ch.len_utf8()
+            });
+
+        // SAFETY: `amount` is measured from the end of the consumed region to the end of a
+        //         `char` within it, or its start.
+        unsafe { self.rewind_unchecked(amount) }
+    }
+
+    /// Advance the cursor to the first remaining [`char`] that matches `pred`, leaving the
+    /// cursor *on* the match rather than past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub fn find<P>(
+        &mut self,
+        mut pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(char) -> bool,
+    {
+        let remaining = self.remaining();
+        let found = remaining
+            .char_indices()
+            .find(|&(_, ch)| pred(ch))
+            .map(|(index, _)| index);
+        let amount = found.unwrap_or(remaining.len());
+
+        // SAFETY: `amount` is the start of a `char` within the remaining region, or its end.
+        unsafe { self.advance_unchecked(amount) };
+
+        found
+    }
+
+    /// Rewind the cursor to just after the last consumed [`char`] that matches `pred`, so
+    /// that the match is the last [`char`] of the consumed region.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the start.
+    #[inline]
+    pub fn rfind<P>(
+        &mut self,
+        mut pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(char) -> bool,
+    {
+        let consumed = self.consumed();
+        let found = consumed
+            .char_indices()
+            .rev()
+            .find(|&(_, ch)| pred(ch))
+            .map(|(index, ch)| consumed.len() - index - ch.len_utf8());
+        let amount = found.unwrap_or(consumed.len());
+
+        // SAFETY: `amount` is measured from the end of the consumed region to the end of a
+        //         `char` within it, or its start.
+        unsafe { self.rewind_unchecked(amount) };
+
+        found
+    }
+}
+
+impl<'a, T> SlideMut<'a, [T]>
+where
+    T: PartialEq,
+{
+    /// Advance the cursor past `prefix` if the remaining region starts with it.
+    ///
+    /// An empty `prefix` always matches, without moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the remaining region started with `prefix`. If not, the cursor
+    /// is not moved.
+    #[inline]
+    pub fn advance_if_prefix(
+        &mut self,
+        prefix: &[T],
+    ) -> bool {
+        if !self.remaining().starts_with(prefix) {
+            return false;
+        }
+
+        // SAFETY: We just checked that the remaining region starts with `prefix`.
+        unsafe { self.advance_unchecked(prefix.len()) };
+
+        true
+    }
+
+    /// Remove consecutive duplicate elements within the remaining region, in place.
+    ///
+    /// This behaves like `Vec::dedup`, but only affects the
+    /// remaining region. The end of this slide is moved so that the remaining region
+    /// only contains the deduplicated elements.
+    ///
+    /// The removed elements are not dropped. They are moved past the new end of the
+    /// slide, where they remain in the underlying buffer in an unspecified order.
+    ///
+    /// As the end of this slide is moved, [`SlideMut::source`], [`SlideMut::source_len`],
+    /// [`SlideMut::source_mut`] and [`SlideMut::into_source`] all shrink to match, and
+    /// the removed elements can no longer be reached through this slide.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new length of the remaining region.
+    #[inline]
+    pub fn dedup_remaining(&mut self) -> usize {
+        let remaining = self.remaining_mut();
+        let len = remaining.len();
+
+        if len <= 1 {
+            return len;
+        }
+
+        // NOTE: Everything before `write` is deduplicated, and everything in `write..read`
+        //       is a duplicate that we've yet to move out of the way.
+        let mut write = 1;
+
+        for read in 1..len {
+            if remaining[read] != remaining[write - 1] {
+                remaining.swap(read, write);
+                write += 1;
+            }
+        }
+
+        // SAFETY: `write <= len`, and any index is a valid split boundary for `[T]`.
+        unsafe { self.raw.truncate_remaining_unchecked(write) };
+
+        write
+    }
+}
+
+/// Write each of `parts` into the remaining region of `out`, one after another, advancing
+/// `out` past everything written.
+///
+/// Nothing is written if the parts do not all fit, in which case `out` is left untouched.
+///
+/// # Returns
+///
+/// Returns whether every part fit within the remaining region of `out`.
+#[inline]
+#[track_caller]
+pub const fn concat_into<T>(
+    out: &mut SlideMut<'_, [T]>,
+    parts: &[&[T]],
+) -> bool
+where
+    T: Copy,
+{
+    let mut total = 0usize;
+    let mut index = 0;
+
+    // NOTE: We can't use `total_len` here, as an overflowing sum just means that the parts
+    //       don't fit, which isn't worth panicking over.
+    while index < parts.len() {
+        total = match total.checked_add(parts[index].len()) {
+            Some(total) if total <= out.raw.remaining_len() => total,
+            _ => return false,
+        };
+
+        index += 1;
+    }
+
+    index = 0;
+
+    while index < parts.len() {
+        let part = parts[index];
+
+        // SAFETY: We know that all of the parts fit within the remaining region, and any
+        //         index is a valid split boundary for `[T]`.
+        let slot = unsafe { out.advance_unchecked(part.len()) };
+
+        slot.copy_from_slice(part);
+        index += 1;
+    }
+
+    true
+}
+
+impl<'a, S> fmt::Debug for SlideMut<'a, S>
+where
+    S: Slice + fmt::Debug + ?Sized,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let (consumed, remaining) = self.split();
+
+        f.debug_struct("SlideMut")
+            .field("consumed", &consumed)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
+
+impl<'a, S> From<&'a mut S> for SlideMut<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn from(slice: &'a mut S) -> Self {
+        SlideMut::new(slice)
+    }
+}
+
+impl<'a, S> From<SlideMut<'a, S>> for Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn from(slide: SlideMut<'a, S>) -> Self {
+        slide.into_slide()
+    }
+}
+
+impl<'a> fmt::Write for SlideMut<'a, [u8]> {
+    /// Copies the bytes of `s` into the remaining region, advancing the cursor past them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remaining region is too small to hold `s`. The position of
+    /// the cursor after an error is unspecified, but it is always valid.
+    #[inline]
+    fn write_str(
+        &mut self,
+        s: &str,
+    ) -> fmt::Result {
+        match self.try_advance(s.len()) {
+            Ok(written) => {
+                written.copy_from_slice(s.as_bytes());
+
+                Ok(())
+            }
+            Err(..) => Err(fmt::Error),
+        }
+    }
+}
+
+// SAFETY: A `SlideMut<'a, S>` is semantically a `&'a mut S`.
+unsafe impl<'a, S> Send for SlideMut<'a, S> where S: Slice + Send + ?Sized {}
+
+// SAFETY: A `SlideMut<'a, S>` is semantically a `&'a mut S`.
+unsafe impl<'a, S> Sync for SlideMut<'a, S> where S: Slice + Sync + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concat_into_fits() {
+        let mut buf = [0u8; 8];
+        let mut out = SlideMut::new(&mut buf[..]);
+
+        out.advance(1);
+
+        assert!(concat_into(&mut out, &[b"ab", b"", b"cde"]));
+        assert_eq!(out.offset(), 6);
+        assert!(concat_into(&mut out, &[b"f", b"g"]));
+        assert!(out.is_at_end());
+        assert!(concat_into(&mut out, &[]));
+        assert_eq!(buf, *b"\0abcdefg");
+    }
+
+    #[test]
+    fn concat_into_does_not_fit() {
+        let mut buf = [0u8; 4];
+        let mut out = SlideMut::new(&mut buf[..]);
+
+        assert!(!concat_into(&mut out, &[b"ab", b"cde"]));
+        assert_eq!(out.offset(), 0);
+        assert_eq!(buf, [0; 4]);
+    }
+
+    #[test]
+    fn concat_into_overflow() {
+        let huge: &[()] = &[(); usize::MAX];
+        let mut buf = [(); usize::MAX];
+        let mut out = SlideMut::new(&mut buf[..]);
+
+        assert!(!concat_into(&mut out, &[huge, huge]));
+        assert_eq!(out.offset(), 0);
+        assert!(concat_into(&mut out, &[huge]));
+        assert!(out.is_at_end());
+    }
+
+    #[test]
+    fn retain_remaining_keeps_order() {
+        let mut buf = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(2);
+
+        assert_eq!(slide.retain_remaining(|&x| x % 2 == 0), 3);
+        assert_eq!(slide.consumed(), [1, 2]);
+        assert_eq!(slide.remaining(), [4, 6, 8]);
+        assert_eq!(slide.source_len(), 5);
+        assert_eq!(slide.source(), [1, 2, 4, 6, 8]);
+        assert_eq!(slide.into_source(), [1, 2, 4, 6, 8]);
+
+        // NOTE: The removed elements are still in the buffer, just past the end.
+        let mut tail = buf[5..].to_vec();
+        tail.sort();
+        assert_eq!(tail, [3, 5, 7]);
+    }
+
+    #[test]
+    fn retain_remaining_none_and_all() {
+        let mut buf = [1u8, 2, 3];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        assert_eq!(slide.retain_remaining(|_| true), 3);
+        assert_eq!(slide.source_len(), 3);
+        assert_eq!(slide.retain_remaining(|_| false), 0);
+        assert_eq!(slide.source_len(), 0);
+        assert!(slide.is_at_end());
+    }
+
+    #[test]
+    fn dedup_remaining_removes_runs() {
+        let mut buf = [0u8, 0, 1, 1, 1, 2, 3, 3, 1];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.advance(1);
+
+        assert_eq!(slide.dedup_remaining(), 5);
+        assert_eq!(slide.consumed(), [0]);
+        assert_eq!(slide.remaining(), [0, 1, 2, 3, 1]);
+        assert_eq!(slide.source_len(), 6);
+        assert_eq!(slide.source(), [0, 0, 1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn dedup_remaining_short() {
+        let mut buf = [7u8];
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        assert_eq!(slide.dedup_remaining(), 1);
+        assert_eq!(slide.source_len(), 1);
+
+        slide.advance(1);
+
+        assert_eq!(slide.dedup_remaining(), 0);
+        assert_eq!(slide.source_len(), 1);
+    }
+}
@@ -0,0 +1,2074 @@
+use core::{
+    cmp, fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ptr::NonNull,
+    slice::{self, SliceIndex},
+    str::{self, Utf8Error},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    bounds::SliceRange,
+    macros::{assert_unchecked, unreachable_unchecked},
+    mem::NoDrop,
+    slice::{
+        Slice, SplitError, as_elems, first, get_elem, last, split_at_checked, split_at_unchecked,
+    },
+    slide::{
+        SlideMut,
+        location::Location,
+        raw::{Movement, RawSlide},
+    },
+    str::{ceil_char_boundary, floor_char_boundary, is_utf8_char_boundary},
+    util::min_usize,
+};
+
+/// A cursor that slides across a shared slice.
+///
+/// A [`Slide`] splits its source into two regions at its cursor:
+///
+/// - The *consumed* region, which lies before the cursor.
+///
+/// - The *remaining* region, which lies at or after the cursor.
+///
+/// The cursor *always* lies on a valid split boundary for `S`. See the
+/// documentation for [`Slice::validate_split_at`] as implemented for `S`
+/// for details on what is considered a valid split boundary.
+#[repr(transparent)]
+pub struct Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// The underlying raw slide.
+    ///
+    /// # Safety
+    ///
+    /// The raw slide must be derived from a `&'a S`.
+    raw: RawSlide<S>,
+    /// Marker for the shared borrow of the source.
+    _marker: PhantomData<&'a S>,
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Create a new [`Slide`] over `slice` from a raw slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `raw` is derived from a `&'a S`.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const unsafe fn from_raw(raw: RawSlide<S>) -> Slide<'a, S> {
+        Slide {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the underlying raw slide.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn into_raw(self) -> RawSlide<S> {
+        self.raw
+    }
+
+    /// Shrink the remaining region to its first `len` elements, by moving the end of this
+    /// slide.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `len` is no greater than the length of the remaining
+    /// region, and that `len` lies on a valid split boundary within it.
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) const unsafe fn truncate_remaining_unchecked(
+        &mut self,
+        len: usize,
+    ) {
+        // SAFETY: The caller ensures that this is sound.
+        unsafe { self.raw.truncate_remaining_unchecked(len) }
+    }
+
+    /// Create a new [`Slide`] over `slice` with the cursor at the start.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn new(slice: &'a S) -> Slide<'a, S> {
+        // SAFETY: It is always valid to split a slice at `0`.
+        unsafe { Slide::with_offset_unchecked(slice, 0) }
+    }
+
+    /// Attempt to create a new [`Slide`] over `slice` with the cursor at `offset`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slide)` upon success.
+    ///
+    /// - `Err(error)` if `offset` is not a valid split boundary for `slice`. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_with_offset(
+        slice: &'a S,
+        offset: usize,
+    ) -> Result<Slide<'a, S>, SplitError<S>> {
+        match NoDrop::new(RawSlide::from_ref(slice, offset)).transpose() {
+            // SAFETY: `raw` is derived from a `&'a S`.
+            Ok(raw) => Ok(unsafe { Slide::from_raw(raw.into_inner()) }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Create a new [`Slide`] over `slice` with the cursor at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is not a valid split boundary for `slice`. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn with_offset(
+        slice: &'a S,
+        offset: usize,
+    ) -> Slide<'a, S> {
+        match Slide::try_with_offset(slice, offset) {
+            Ok(slide) => slide,
+            Err(error) => Slide::new(slice)
+                .raw
+                .context_panic(Movement::WithOffset, offset, error),
+        }
+    }
+
+    /// Create a new [`Slide`] over `slice` with the cursor at `offset` without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `offset` is a valid split boundary for `slice`. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    /// Despite this method not doing any checks on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn with_offset_unchecked(
+        slice: &'a S,
+        offset: usize,
+    ) -> Slide<'a, S> {
+        match NoDrop::new(Slide::try_with_offset(slice, offset)).transpose() {
+            Ok(slide) => slide.into_inner(),
+            // SAFETY: The caller ensures that `offset` is a valid split boundary.
+            Err(error) => unsafe { error.into_inner().panic_unchecked() },
+        }
+    }
+
+    /// Create a new [`Slide`] over the slice pointed to by `source` with the cursor at `offset`.
+    ///
+    /// This is primarily intended for FFI, where one may have a pointer and a length rather
+    /// than a reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - `source` points to a single, valid *allocated object* that is properly initialized
+    ///   for `S`, with the length carried by `source` being correct.
+    ///
+    /// - `source` is valid to be read from for `'a`, and that `'a` does not outlive the
+    ///   allocation.
+    ///
+    /// - The memory pointed to by `source` is not mutated through any other pointer for `'a`,
+    ///   as it is semantically borrowed as a `&'a S`.
+    ///
+    /// - `offset` is a valid split boundary for the slice pointed to by `source`. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    /// Despite this method not checking `offset` on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn from_nonnull(
+        source: NonNull<S>,
+        offset: usize,
+    ) -> Slide<'a, S> {
+        // SAFETY: The caller ensures that `source` is a valid `S`.
+        match NoDrop::new(unsafe { RawSlide::new(source, offset) }).transpose() {
+            // SAFETY: The caller ensures that `source` is semantically a `&'a S`.
+            Ok(raw) => unsafe { Slide::from_raw(raw.into_inner()) },
+            // SAFETY: The caller ensures that `offset` is a valid split boundary.
+            Err(error) => unsafe { error.into_inner().panic_unchecked() },
+        }
+    }
+
+    /// Returns whether [`Slide`]s over `S` track their cursor with indices rather than pointers.
+    ///
+    /// Slides are index-based when:
+    ///
+    /// - `S::Elem` is a zero sized type.
+    ///
+    /// - Debug assertions are enabled, in order to catch *undefined behavior*.
+    ///
+    /// - The `force_index` feature is enabled.
+    ///
+    /// Otherwise, slides are pointer-based.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_index_based() -> bool {
+        Location::<S>::INDEX_BASED
+    }
+
+    /// Tell the compiler that there are at least `amount` remaining elements.
+    ///
+    /// This does nothing on its own, but can be used to inject an invariant that is
+    /// known to hold, in order to get better codegen for subsequent operations, such as
+    /// a batch of unchecked peeks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are at least `amount` remaining elements.
+    ///
+    /// Despite this method not doing any checks on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    #[inline(always)]
+    #[track_caller]
+    pub const unsafe fn assume_remaining_at_least(
+        &self,
+        amount: usize,
+    ) {
+        self.raw.compiler_hints();
+
+        // SAFETY: The caller ensures that there are at least `amount` remaining elements.
+        unsafe {
+            assert_unchecked!(
+                self.raw.remaining_len() >= amount,
+                "`remaining_len < amount`"
+            )
+        };
+    }
+
+    /// Returns the offset of the cursor from the start of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.raw.consumed_len()
+    }
+
+    /// Returns the index of the cursor within the source.
+    ///
+    /// This is an alias for [`Self::offset`], for translating between indices relative
+    /// to the cursor and indices into the source: `source_index() + i` is the source
+    /// index of the remaining element at `i`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_index(&self) -> usize {
+        self.offset()
+    }
+
+    /// Returns the length of the entire source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_len(&self) -> usize {
+        self.raw.entire_len()
+    }
+
+    /// Returns the length of the consumed region.
+    ///
+    /// This is always equal to the offset of the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed_len(&self) -> usize {
+        self.raw.consumed_len()
+    }
+
+    /// Returns the length of the remaining region.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining_len(&self) -> usize {
+        self.raw.remaining_len()
+    }
+
+    /// Returns whether the cursor is at the start of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_start(&self) -> bool {
+        self.raw.consumed_len() == 0
+    }
+
+    /// Returns whether the cursor is at the end of the source.
+    ///
+    /// This is an alias for [`Self::is_exhausted`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_end(&self) -> bool {
+        self.is_exhausted()
+    }
+
+    /// Returns whether there are no remaining elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.raw.remaining_len() == 0
+    }
+
+    /// Returns whether the cursor is at the start of the source.
+    ///
+    /// This is an alias for [`Self::is_at_start`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn at_start(&self) -> bool {
+        self.is_at_start()
+    }
+
+    /// Returns whether the cursor is at the end of the source.
+    ///
+    /// This is an alias for [`Self::is_exhausted`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn at_end(&self) -> bool {
+        self.is_exhausted()
+    }
+
+    /// Returns the entire source slice.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn source(&self) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        unsafe { self.raw.entire_ref() }
+    }
+
+    /// Returns the consumed region, the region before the cursor.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed(&self) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        unsafe { self.raw.consumed_ref() }
+    }
+
+    /// Returns the remaining region, the region at or after the cursor.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining(&self) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        unsafe { self.raw.remaining_ref() }
+    }
+
+    /// Returns the size of the entire source, in bytes.
+    ///
+    /// For zero sized types, this is always `0`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn byte_len(&self) -> usize {
+        // NOTE: This can't overflow, as no allocated object may be larger than `isize::MAX` bytes.
+        self.raw.entire_len() * size_of::<S::Elem>()
+    }
+
+    /// Returns the size of the consumed region, in bytes.
+    ///
+    /// For zero sized types, this is always `0`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed_bytes(&self) -> usize {
+        self.raw.consumed_len() * size_of::<S::Elem>()
+    }
+
+    /// Returns the size of the remaining region, in bytes.
+    ///
+    /// For zero sized types, this is always `0`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining_bytes(&self) -> usize {
+        self.raw.remaining_len() * size_of::<S::Elem>()
+    }
+
+    /// Returns the first element of the remaining region, the element at the cursor.
+    ///
+    /// For [`prim@str`]s, this is a byte.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_first(&self) -> Option<&'a S::Elem> {
+        first(self.remaining())
+    }
+
+    /// Returns the last element of the remaining region, the last element of the source.
+    ///
+    /// For [`prim@str`]s, this is a byte.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_last(&self) -> Option<&'a S::Elem> {
+        last(self.remaining())
+    }
+
+    /// Returns the first element of the consumed region, the first element of the source.
+    ///
+    /// For [`prim@str`]s, this is a byte.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_first(&self) -> Option<&'a S::Elem> {
+        first(self.consumed())
+    }
+
+    /// Returns the last element of the consumed region, the element before the cursor.
+    ///
+    /// For [`prim@str`]s, this is a byte.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_last(&self) -> Option<&'a S::Elem> {
+        last(self.consumed())
+    }
+
+    /// Returns a new slide over the remaining region, with its cursor at the start.
+    ///
+    /// Unlike [`Slide::remaining`], the returned slide is bound to the borrow of `self`,
+    /// which makes it suitable for handing the tail off to a helper.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_slide(&self) -> Slide<'_, S> {
+        Slide::new(self.remaining())
+    }
+
+    /// Returns a new slide over the consumed region, with its cursor at the start.
+    ///
+    /// This is useful for re-reading what has already been parsed. The returned slide is
+    /// bound to the borrow of `self`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed_slide(&self) -> Slide<'_, S> {
+        Slide::new(self.consumed())
+    }
+
+    /// Returns a new slide over the consumed region, with its cursor at the start, taking
+    /// ownership of `self`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn into_consumed_slide(self) -> Slide<'a, S> {
+        Slide::new(self.consumed())
+    }
+
+    /// Split the remaining region at `mid`, returning a new slide over each half.
+    ///
+    /// This does not move the cursor, which makes it useful for forking a parser into
+    /// two independent halves.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((head, tail))` upon success, where both slides have their cursor at the start.
+    ///
+    /// - `None` if `mid` is out of bounds (`mid > remaining_len`), or is not a valid split
+    ///   boundary. See the documentation for [`Slice::validate_split_at`] as implemented
+    ///   for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn split_at_slides(
+        &self,
+        mid: usize,
+    ) -> Option<(Slide<'a, S>, Slide<'a, S>)> {
+        match split_at_checked(self.remaining(), mid) {
+            Some((head, tail)) => Some((Slide::new(head), Slide::new(tail))),
+            None => None,
+        }
+    }
+
+    /// Returns the source split at the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed, remaining)`.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn split(&self) -> (&'a S, &'a S) {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        unsafe { self.raw.split_ref() }
+    }
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Attempt to peek ahead of the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the first `amount` elements of
+    ///   the remaining region.
+    ///
+    /// - `Err(error)` if it is invalid to peek ahead by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek(
+        &self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        match NoDrop::new(unsafe { self.raw.try_peek_ahead(amount) }).transpose() {
+            // SAFETY: The peeked region is borrowed from a `&'a S`.
+            Ok(peeked) => Ok(unsafe { peeked.into_inner().as_ref() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Peek ahead of the cursor by `amount` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek ahead by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek(
+        &self,
+        amount: usize,
+    ) -> &'a S {
+        match self.try_peek(amount) {
+            Ok(peeked) => peeked,
+            Err(error) => self.raw.context_panic(Movement::Peek, amount, error),
+        }
+    }
+
+    /// Peek ahead of the cursor by `amount` elements without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek ahead by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_unchecked(
+        &self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`, so the peeked region is too,
+        //         and the caller ensures that it is valid to peek ahead by `amount`.
+        unsafe { self.raw.peek_ahead_unchecked(amount).as_ref() }
+    }
+
+    /// Attempt to peek behind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(peeked)` upon success, where `peeked` is the last `amount` elements of
+    ///   the consumed region.
+    ///
+    /// - `Err(error)` if it is invalid to peek behind by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const fn try_peek_back(
+        &self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        match NoDrop::new(unsafe { self.raw.try_peek_behind(amount) }).transpose() {
+            // SAFETY: The peeked region is borrowed from a `&'a S`.
+            Ok(peeked) => Ok(unsafe { peeked.into_inner().as_ref() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Peek behind the cursor by `amount` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to peek behind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn peek_back(
+        &self,
+        amount: usize,
+    ) -> &'a S {
+        match self.try_peek_back(amount) {
+            Ok(peeked) => peeked,
+            Err(error) => self.raw.context_panic(Movement::PeekBack, amount, error),
+        }
+    }
+
+    /// Peek behind the cursor by `amount` elements without any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to peek behind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const unsafe fn peek_back_unchecked(
+        &self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`, so the peeked region is too,
+        //         and the caller ensures that it is valid to peek behind by `amount`.
+        unsafe { self.raw.peek_behind_unchecked(amount).as_ref() }
+    }
+
+    /// Attempt to advance the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(advanced)` upon success, where `advanced` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if it is invalid to advance by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub const fn try_advance(
+        &mut self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        match NoDrop::new(unsafe { self.raw.try_advance(amount) }).transpose() {
+            // SAFETY: The advanced region is borrowed from a `&'a S`.
+            Ok(advanced) => Ok(unsafe { advanced.into_inner().as_ref() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Advance the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to advance by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn advance(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        match self.try_advance(amount) {
+            Ok(advanced) => advanced,
+            Err(error) => self.raw.context_panic(Movement::Advance, amount, error),
+        }
+    }
+
+    /// Advance the cursor by `amount` elements without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to advance by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`, so the advanced region is too,
+        //         and the caller ensures that it is valid to advance by `amount`.
+        unsafe { self.raw.advance_unchecked(amount).as_ref() }
+    }
+
+    /// Attempt to rewind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(rewound)` upon success, where `rewound` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if it is invalid to rewind by `amount` elements. See the
+    ///   documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub const fn try_rewind(
+        &mut self,
+        amount: usize,
+    ) -> Result<&'a S, SplitError<S>> {
+        // SAFETY: The raw slide is derived from a `&'a S`.
+        match NoDrop::new(unsafe { self.raw.try_rewind(amount) }).transpose() {
+            // SAFETY: The rewound region is borrowed from a `&'a S`.
+            Ok(rewound) => Ok(unsafe { rewound.into_inner().as_ref() }),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+
+    /// Rewind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it is invalid to rewind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn rewind(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        match self.try_rewind(amount) {
+            Ok(rewound) => rewound,
+            Err(error) => self.raw.context_panic(Movement::Rewind, amount, error),
+        }
+    }
+
+    /// Rewind the cursor by `amount` elements without any checks.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that it is valid to rewind by `amount` elements. See the
+    /// documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn rewind_unchecked(
+        &mut self,
+        amount: usize,
+    ) -> &'a S {
+        // SAFETY: The raw slide is derived from a `&'a S`, so the rewound region is too,
+        //         and the caller ensures that it is valid to rewind by `amount`.
+        unsafe { self.raw.rewind_unchecked(amount).as_ref() }
+    }
+
+    /// Advance the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(advanced)` upon success, where `advanced` is the region the cursor moved over.
+    ///
+    /// - `None` if `offset` is before the cursor, or if it is invalid to advance to
+    ///   `offset`. See the documentation for [`Slice::validate_split_at`] as implemented
+    ///   for `S` for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn advance_to_checked(
+        &mut self,
+        offset: usize,
+    ) -> Option<&'a S> {
+        let Some(amount) = offset.checked_sub(self.offset()) else {
+            return None;
+        };
+
+        match NoDrop::new(self.try_advance(amount)).transpose() {
+            Ok(advanced) => Some(advanced.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Advance the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is before the cursor, or if it is invalid to advance to `offset`.
+    /// See the documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn advance_to(
+        &mut self,
+        offset: usize,
+    ) -> &'a S {
+        let current = self.offset();
+
+        match offset.checked_sub(current) {
+            Some(amount) => self.advance(amount),
+            None => panic!("advance_to({offset}) but the cursor is at offset {current}"),
+        }
+    }
+
+    /// Rewind the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(rewound)` upon success, where `rewound` is the region the cursor moved over.
+    ///
+    /// - `None` if `offset` is after the cursor, or if it is invalid to rewind to `offset`.
+    ///   See the documentation for [`Slice::validate_split_at`] as implemented for `S`
+    ///   for details.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn rewind_to_checked(
+        &mut self,
+        offset: usize,
+    ) -> Option<&'a S> {
+        let Some(amount) = self.offset().checked_sub(offset) else {
+            return None;
+        };
+
+        match NoDrop::new(self.try_rewind(amount)).transpose() {
+            Ok(rewound) => Some(rewound.into_inner()),
+            Err(..) => None,
+        }
+    }
+
+    /// Rewind the cursor to the absolute `offset`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is after the cursor, or if it is invalid to rewind to `offset`.
+    /// See the documentation for [`Slice::validate_split_at`] as implemented for `S` for details.
+    #[inline]
+    #[track_caller]
+    pub fn rewind_to(
+        &mut self,
+        offset: usize,
+    ) -> &'a S {
+        let current = self.offset();
+
+        match current.checked_sub(offset) {
+            Some(amount) => self.rewind(amount),
+            None => panic!("rewind_to({offset}) but the cursor is at offset {current}"),
+        }
+    }
+}
+
+impl<'a, T> Slide<'a, [T]> {
+    /// Create a new [`Slide`] over the current contents of `vec`, with the cursor at
+    /// the start.
+    ///
+    /// The slide views the elements of `vec` at the time of creation, not its capacity.
+    /// Since the slide borrows `vec`, it cannot be modified while the slide is in use.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_vec(vec: &'a Vec<T>) -> Slide<'a, [T]> {
+        Slide::new(vec.as_slice())
+    }
+
+    /// Create a new [`Slide`] over `source` with the cursor at the start, if the start of
+    /// `source` is aligned to `align` bytes.
+    ///
+    /// This allows for deciding on alignment once, up front, such that later reads which
+    /// rely on it are justified.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(slide)` if `source` is aligned to `align` bytes.
+    ///
+    /// - `None` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn new_aligned(
+        source: &'a [T],
+        align: usize,
+    ) -> Option<Slide<'a, [T]>> {
+        assert!(align.is_power_of_two(), "`align` is not a power of two");
+
+        if source.as_ptr().addr() & (align - 1) == 0 {
+            Some(Slide::new(source))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the next remaining element, if any, without moving the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn peek_one(&self) -> Option<&'a T> {
+        self.remaining().first()
+    }
+
+    /// Returns the last consumed element, if any, without moving the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn peek_one_back(&self) -> Option<&'a T> {
+        self.consumed().last()
+    }
+
+    /// Returns the remaining element `index` elements after the cursor, if any, without
+    /// moving the cursor.
+    ///
+    /// A `peek_at(0)` is equivalent to `peek_one()`.
+    #[inline]
+    #[must_use]
+    pub const fn peek_at(
+        &self,
+        index: usize,
+    ) -> Option<&'a T> {
+        get_elem(self.remaining(), index)
+    }
+
+    /// Returns the consumed element `index` elements before the cursor, if any, without
+    /// moving the cursor.
+    ///
+    /// A `peek_back_at(0)` is equivalent to `peek_one_back()`.
+    #[inline]
+    #[must_use]
+    pub const fn peek_back_at(
+        &self,
+        index: usize,
+    ) -> Option<&'a T> {
+        let consumed = self.consumed();
+
+        if index < consumed.len() {
+            Some(&consumed[consumed.len() - 1 - index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the element or subslice of the remaining region at `index`, if any.
+    ///
+    /// Note that `index` is relative to the cursor, *not* to the start of the source. So
+    /// `get(0)` is the first remaining element, regardless of where the cursor lies.
+    #[inline]
+    #[must_use]
+    pub fn get<I>(
+        &self,
+        index: I,
+    ) -> Option<&'a I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.remaining().get(index)
+    }
+
+    /// Returns the element or subslice of the consumed region at `index`, if any.
+    ///
+    /// As the consumed region starts at the start of the source, `index` is effectively
+    /// absolute, but only elements before the cursor can be reached.
+    #[inline]
+    #[must_use]
+    pub fn get_consumed<I>(
+        &self,
+        index: I,
+    ) -> Option<&'a I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.consumed().get(index)
+    }
+
+    /// Returns the element or subslice of the source at `index`, if any.
+    ///
+    /// Unlike [`Slide::get`], `index` is absolute, and is relative to the start of the source
+    /// rather than to the cursor.
+    #[inline]
+    #[must_use]
+    pub fn source_get<I>(
+        &self,
+        index: I,
+    ) -> Option<&'a I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.source().get(index)
+    }
+
+    /// Advance the cursor by `amount` elements, or as far as possible.
+    ///
+    /// This mirrors [`Iterator::advance_by`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the cursor was advanced by exactly `amount` elements.
+    ///
+    /// - `Err(leftover)` if there were less than `amount` remaining elements, where
+    ///   `leftover` is the amount of elements the cursor could not be advanced by.
+    ///
+    ///   The cursor is moved to the end upon failure.
+    #[inline]
+    pub const fn advance_by(
+        &mut self,
+        amount: usize,
+    ) -> Result<(), usize> {
+        let remaining = self.raw.remaining_len();
+
+        if amount <= remaining {
+            // SAFETY: We just checked that there are at least `amount` remaining elements.
+            unsafe { self.advance_unchecked(amount) };
+
+            Ok(())
+        } else {
+            // SAFETY: It is always valid to advance to the end.
+            unsafe { self.advance_unchecked(remaining) };
+
+            // SAFETY: We know that `amount > remaining`.
+            Err(unsafe { amount.unchecked_sub(remaining) })
+        }
+    }
+
+    /// Rewind the cursor by `amount` elements, or as far as possible.
+    ///
+    /// This mirrors [`DoubleEndedIterator::advance_back_by`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the cursor was rewound by exactly `amount` elements.
+    ///
+    /// - `Err(leftover)` if there were less than `amount` consumed elements, where
+    ///   `leftover` is the amount of elements the cursor could not be rewound by.
+    ///
+    ///   The cursor is moved to the start upon failure.
+    #[inline]
+    pub const fn rewind_by(
+        &mut self,
+        amount: usize,
+    ) -> Result<(), usize> {
+        let consumed = self.raw.consumed_len();
+
+        if amount <= consumed {
+            // SAFETY: We just checked that there are at least `amount` consumed elements.
+            unsafe { self.rewind_unchecked(amount) };
+
+            Ok(())
+        } else {
+            // SAFETY: It is always valid to rewind to the start.
+            unsafe { self.rewind_unchecked(consumed) };
+
+            // SAFETY: We know that `amount > consumed`.
+            Err(unsafe { amount.unchecked_sub(consumed) })
+        }
+    }
+
+    /// Returns the first `N` remaining elements as an array, along with a copy of this slide
+    /// advanced past them.
+    ///
+    /// This slide is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((chunk, slide))` upon success.
+    ///
+    /// - `None` if there are less than `N` remaining elements.
+    #[inline]
+    #[must_use]
+    pub const fn split_first_chunk<const N: usize>(&self) -> Option<(&'a [T; N], Slide<'a, [T]>)> {
+        if self.raw.remaining_len() < N {
+            return None;
+        }
+
+        let mut slide = *self;
+
+        // SAFETY: We just checked that there are at least `N` remaining elements.
+        let chunk = unsafe { slide.advance_unchecked(N) };
+
+        // SAFETY: `chunk` contains exactly `N` elements.
+        let chunk = unsafe { &*chunk.as_ptr().cast::<[T; N]>() };
+
+        Some((chunk, slide))
+    }
+
+    /// Returns the range that the source of this slide occupies within `parent`.
+    ///
+    /// This is useful for mapping positions within a slide that was carved from a larger
+    /// buffer back to the coordinates of that buffer.
+    ///
+    /// Since zero sized types all share the same address, for those the source is assumed
+    /// to lie at the start of `parent`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(range)` if the source lies within `parent`.
+    ///
+    /// - `None` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn range_in(
+        &self,
+        parent: &[T],
+    ) -> Option<SliceRange> {
+        let source = self.source();
+
+        if size_of::<T>() == 0 {
+            return (source.len() <= parent.len()).then(|| SliceRange::up_to(source.len()));
+        }
+
+        let parent_range = parent.as_ptr_range();
+        let source_range = source.as_ptr_range();
+
+        if source_range.start < parent_range.start || source_range.end > parent_range.end {
+            return None;
+        }
+
+        // SAFETY: We just checked that `source` lies within `parent`.
+        let start = unsafe { source_range.start.offset_from_unsigned(parent_range.start) };
+
+        // SAFETY: Since `source` lies within `parent`, this can never overflow.
+        let end = unsafe { start.unchecked_add(source.len()) };
+
+        // SAFETY: We know that `start <= end`.
+        Some(unsafe { SliceRange::new_unchecked(start, end) })
+    }
+
+    /// Advance the cursor to `ptr`, a pointer into the remaining region or to its end.
+    ///
+    /// This allows for external routines, such as a SIMD scan that returns the address of
+    /// a match, to drive the cursor.
+    ///
+    /// Since zero sized types all share the same address, for those the cursor is left
+    /// where it is.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `ptr` is derived from the source of this slide, lies
+    /// within `cursor..=end`, and is a whole amount of elements away from the start of the
+    /// source.
+    ///
+    /// Despite this method not checking `ptr` on release builds, it *does* actually do
+    /// checks on debug builds to catch *undefined behavior*.
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly consumed region.
+    #[inline]
+    #[track_caller]
+    pub const unsafe fn advance_to_ptr(
+        &mut self,
+        ptr: *const T,
+    ) -> &'a [T] {
+        if size_of::<T>() == 0 {
+            // SAFETY: It is always valid to advance by zero.
+            return unsafe { self.advance_unchecked(0) };
+        }
+
+        // SAFETY: The caller ensures that `ptr` lies within the source, at an element boundary.
+        let offset = unsafe { ptr.offset_from_unsigned(self.source().as_ptr()) };
+
+        // SAFETY: The caller ensures that `ptr` lies at or after the cursor.
+        unsafe { assert_unchecked!(offset >= self.offset(), "`ptr` lies before the cursor") };
+
+        // SAFETY: The caller ensures that `ptr` lies at or before the end, and we just
+        //         ensured that `offset >= self.offset()`.
+        unsafe { self.advance_unchecked(offset.unchecked_sub(self.offset())) }
+    }
+
+    /// Split the remaining region at `remaining_len * numer / denom`, returning a new
+    /// slide over each half.
+    ///
+    /// The split index is clamped to the length of the remaining region. If `denom == 0`,
+    /// then the split occurs at the end of the remaining region.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(head, tail)`, where both slides have their cursor at the start.
+    #[inline]
+    #[must_use]
+    pub const fn split_at_ratio(
+        &self,
+        numer: usize,
+        denom: usize,
+    ) -> (Slide<'a, [T]>, Slide<'a, [T]>) {
+        let remaining = self.remaining();
+        let mid = ratio_index(remaining.len(), numer, denom);
+
+        // SAFETY: `ratio_index` never returns an index greater than the length.
+        let (head, tail) = unsafe { split_at_unchecked(remaining, mid) };
+
+        (Slide::new(head), Slide::new(tail))
+    }
+
+    /// Split the remaining region into `N` element arrays, starting from the cursor,
+    /// and a remainder with a length less than `N`, without moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(chunks, remainder)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N == 0`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining_as_chunks<const N: usize>(&self) -> (&'a [[T; N]], &'a [T]) {
+        self.remaining().as_chunks()
+    }
+
+    /// Advance the cursor past as many `N` element arrays as possible, leaving the
+    /// remainder with a length less than `N`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the chunks the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N == 0`.
+    #[inline]
+    #[track_caller]
+    pub const fn advance_as_chunks<const N: usize>(&mut self) -> &'a [[T; N]] {
+        let (chunks, _) = self.remaining_as_chunks::<N>();
+
+        // SAFETY: The chunks lie at the start of the remaining region, so it is valid
+        //         to advance past them.
+        unsafe { self.advance_unchecked(chunks.len() * N) };
+
+        chunks
+    }
+
+    /// Returns the last `N` consumed elements as an array, along with a copy of this slide
+    /// rewound past them.
+    ///
+    /// This slide is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((chunk, slide))` upon success.
+    ///
+    /// - `None` if there are less than `N` consumed elements.
+    #[inline]
+    #[must_use]
+    pub const fn split_last_chunk<const N: usize>(&self) -> Option<(&'a [T; N], Slide<'a, [T]>)> {
+        if self.raw.consumed_len() < N {
+            return None;
+        }
+
+        let mut slide = *self;
+
+        // SAFETY: We just checked that there are at least `N` consumed elements.
+        let chunk = unsafe { slide.rewind_unchecked(N) };
+
+        // SAFETY: `chunk` contains exactly `N` elements.
+        let chunk = unsafe { &*chunk.as_ptr().cast::<[T; N]>() };
+
+        Some((chunk, slide))
+    }
+
+    /// Attempt to advance the cursor by `amount` elements, calling `f` on each element
+    /// moved over, in order.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(advanced)` upon success, where `advanced` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if there are less than `amount` remaining elements, in which case
+    ///   neither the cursor is moved nor is `f` called.
+    #[inline]
+    pub fn try_advance_for_each<F>(
+        &mut self,
+        amount: usize,
+        f: F,
+    ) -> Result<&'a [T], SplitError<[T]>>
+    where
+        F: FnMut(&'a T),
+    {
+        let advanced = self.try_advance(amount)?;
+
+        advanced.iter().for_each(f);
+
+        Ok(advanced)
+    }
+
+    /// Advance the cursor by `amount` elements, calling `f` on each element moved over,
+    /// in order.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `amount` remaining elements.
+    #[inline]
+    #[track_caller]
+    pub fn advance_for_each<F>(
+        &mut self,
+        amount: usize,
+        f: F,
+    ) -> &'a [T]
+    where
+        F: FnMut(&'a T),
+    {
+        let advanced = self.advance(amount);
+
+        advanced.iter().for_each(f);
+
+        advanced
+    }
+
+    /// Advance the cursor by `amount` elements, or as far as possible, calling `f` on each
+    /// element moved over, in order.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    #[inline]
+    pub fn advance_for_each_saturating<F>(
+        &mut self,
+        amount: usize,
+        f: F,
+    ) -> &'a [T]
+    where
+        F: FnMut(&'a T),
+    {
+        let amount = min_usize(amount, self.raw.remaining_len());
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        let advanced = unsafe { self.advance_unchecked(amount) };
+
+        advanced.iter().for_each(f);
+
+        advanced
+    }
+
+    /// Advance the cursor for as long as the next remaining element matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn advance_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &'a [T]
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let amount = self
+            .remaining()
+            .iter()
+            .position(|elem| !pred(elem))
+            .unwrap_or(self.raw.remaining_len());
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(amount) }
+    }
+
+    /// Rewind the cursor for as long as the last consumed element matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn rewind_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &'a [T]
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let consumed = self.consumed();
+        let amount = consumed
+            .iter()
+            .rev()
+            .position(|elem| !pred(elem))
+            .unwrap_or(consumed.len());
+
+        // SAFETY: `amount` is never greater than the length of the consumed region.
+        unsafe { self.rewind_unchecked(amount) }
+    }
+
+    /// Advance the cursor to the first remaining element that matches `pred`, leaving the
+    /// cursor *on* the match rather than past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of elements skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub fn find<P>(
+        &mut self,
+        pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let found = self.remaining().iter().position(pred);
+        let amount = found.unwrap_or(self.remaining_len());
+
+        // SAFETY: `amount` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(amount) };
+
+        found
+    }
+
+    /// Rewind the cursor to just after the last consumed element that matches `pred`, so
+    /// that the match is the last element of the consumed region.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of elements skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the start.
+    #[inline]
+    pub fn rfind<P>(
+        &mut self,
+        pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let found = self.consumed().iter().rev().position(pred);
+        let amount = found.unwrap_or(self.consumed_len());
+
+        // SAFETY: `amount` is never greater than the length of the consumed region.
+        unsafe { self.rewind_unchecked(amount) };
+
+        found
+    }
+
+    /// Returns up to `before` elements preceding the cursor and up to `after` elements
+    /// following it.
+    ///
+    /// This is useful for rendering the surroundings of the cursor in diagnostics.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(consumed_tail, remaining_head)`.
+    #[inline]
+    #[must_use]
+    pub const fn context(
+        &self,
+        before: usize,
+        after: usize,
+    ) -> (&'a [T], &'a [T]) {
+        let (consumed, remaining) = self.split();
+
+        let before = min_usize(before, consumed.len());
+        let after = min_usize(after, remaining.len());
+
+        // SAFETY: `before` is never greater than the length of the consumed region.
+        let (_, consumed_tail) = unsafe { consumed.split_at_unchecked(consumed.len() - before) };
+        // SAFETY: `after` is never greater than the length of the remaining region.
+        let (remaining_head, _) = unsafe { remaining.split_at_unchecked(after) };
+
+        (consumed_tail, remaining_head)
+    }
+
+    /// Returns a contiguous window of the source spanning up to `radius` elements on
+    /// either side of the cursor.
+    ///
+    /// This is the data backing a `^`-style pointer in parser diagnostics.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(window, position)`, where `position` is the offset of the
+    /// cursor within `window`.
+    #[inline]
+    #[must_use]
+    pub const fn error_window(
+        &self,
+        radius: usize,
+    ) -> (&'a [T], usize) {
+        let (consumed_tail, remaining_head) = self.context(radius, radius);
+        let position = consumed_tail.len();
+
+        // SAFETY: Both halves are adjacent within the source, so together they form a
+        //         single contiguous slice within it.
+        let window = unsafe {
+            slice::from_raw_parts(
+                consumed_tail.as_ptr(),
+                position.unchecked_add(remaining_head.len()),
+            )
+        };
+
+        (window, position)
+    }
+
+    /// Apply `f` to each remaining element, writing the results into the remaining region
+    /// of `out` and advancing its cursor past them.
+    ///
+    /// This stops once either this slide or `out` has no remaining elements left. The
+    /// cursor of this slide is not moved, which allows for the same transform to be
+    /// rerun.
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount of elements produced.
+    #[inline]
+    pub fn map_into<U, F>(
+        &self,
+        out: &mut SlideMut<'_, [U]>,
+        mut f: F,
+    ) -> usize
+    where
+        F: FnMut(&T) -> U,
+    {
+        let count = cmp::min(self.raw.remaining_len(), out.remaining().len());
+        let dst = &mut out.remaining_mut()[..count];
+
+        for (dst, src) in dst.iter_mut().zip(self.remaining()) {
+            *dst = f(src);
+        }
+
+        // SAFETY: `count` is never greater than the length of the remaining region of `out`.
+        unsafe { out.advance_unchecked(count) };
+
+        count
+    }
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+    S::Elem: Hash,
+{
+    /// Feed the elements of the consumed region into `state`.
+    ///
+    /// Unlike hashing the region itself, no length prefix is written. For byte slides,
+    /// this results in a single call to [`Hasher::write`].
+    #[inline]
+    pub fn hash_consumed<H>(
+        &self,
+        state: &mut H,
+    ) where
+        H: Hasher,
+    {
+        Hash::hash_slice(as_elems(self.consumed()), state);
+    }
+
+    /// Feed the elements of the remaining region into `state`.
+    ///
+    /// Unlike hashing the region itself, no length prefix is written. For byte slides,
+    /// this results in a single call to [`Hasher::write`].
+    #[inline]
+    pub fn hash_remaining<H>(
+        &self,
+        state: &mut H,
+    ) where
+        H: Hasher,
+    {
+        Hash::hash_slice(as_elems(self.remaining()), state);
+    }
+}
+
+impl<'a, T> Slide<'a, [T]>
+where
+    T: PartialEq,
+{
+    /// Advance the cursor past `prefix` if the remaining region starts with it.
+    ///
+    /// An empty `prefix` always matches, without moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the remaining region started with `prefix`. If not, the cursor
+    /// is not moved.
+    #[inline]
+    pub fn advance_if_prefix(
+        &mut self,
+        prefix: &[T],
+    ) -> bool {
+        if !self.remaining().starts_with(prefix) {
+            return false;
+        }
+
+        // SAFETY: We just checked that the remaining region starts with `prefix`.
+        unsafe { self.advance_unchecked(prefix.len()) };
+
+        true
+    }
+
+    /// Returns the length of the run of elements equal to `value` at the start of the
+    /// remaining region, without moving the cursor.
+    #[inline]
+    #[must_use]
+    pub fn peek_run_of(
+        &self,
+        value: &T,
+    ) -> usize {
+        self.remaining()
+            .iter()
+            .position(|elem| elem != value)
+            .unwrap_or(self.raw.remaining_len())
+    }
+
+    /// Advance the cursor past the run of elements equal to `value` at the start of the
+    /// remaining region.
+    ///
+    /// # Returns
+    ///
+    /// Returns the length of the run.
+    #[inline]
+    pub fn advance_run_of(
+        &mut self,
+        value: &T,
+    ) -> usize {
+        let run = self.peek_run_of(value);
+
+        // SAFETY: `run` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(run) };
+
+        run
+    }
+}
+
+impl<'a, T> Slide<'a, [T]>
+where
+    T: Copy,
+{
+    /// Advance the cursor over the leading remaining elements that match `pred`, copying
+    /// them into the remaining region of `out` and advancing its cursor by the same amount.
+    ///
+    /// This stops at the first element that does not match `pred`, or once `out` has no
+    /// remaining elements left. Elements past that point are never passed to `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount of elements copied.
+    #[inline]
+    pub fn advance_while_copying<P>(
+        &mut self,
+        mut pred: P,
+        out: &mut SlideMut<'_, [T]>,
+    ) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let limit = cmp::min(self.raw.remaining_len(), out.remaining().len());
+        let count = self.remaining()[..limit]
+            .iter()
+            .position(|elem| !pred(elem))
+            .unwrap_or(limit);
+
+        // SAFETY: `count` is never greater than the length of our remaining region.
+        let src = unsafe { self.advance_unchecked(count) };
+        // SAFETY: `count` is never greater than the length of the remaining region of `out`.
+        let dst = unsafe { out.advance_unchecked(count) };
+
+        dst.copy_from_slice(src);
+
+        count
+    }
+}
+
+impl<'a> Slide<'a, [u8]> {
+    /// Create a new byte slide over the bytes of `s`, with the cursor at the start.
+    ///
+    /// See [`Slide::consumed_str`] and [`Slide::remaining_str`] for getting text back
+    /// out of the slide.
+    #[allow(clippy::should_implement_trait)]
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_str(s: &'a str) -> Slide<'a, [u8]> {
+        Slide::new(s.as_bytes())
+    }
+
+    /// Advance the cursor past a UTF-8 byte order mark (`EF BB BF`) at the start of the
+    /// remaining region, if present.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether a byte order mark was skipped. If not, the cursor is not moved.
+    #[inline]
+    pub const fn skip_utf8_bom(&mut self) -> bool {
+        if let [0xEF, 0xBB, 0xBF, ..] = self.remaining() {
+            // SAFETY: We just checked that there are at least three remaining elements.
+            unsafe { self.advance_unchecked(3) };
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Advance the cursor past a shebang line (`#!...`) at the start of the remaining
+    /// region, if present.
+    ///
+    /// The terminating `\n` is skipped as well. If there is none, the cursor is moved to
+    /// the end.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether a shebang line was skipped. If not, the cursor is not moved.
+    #[inline]
+    pub const fn skip_shebang(&mut self) -> bool {
+        let remaining = self.remaining();
+
+        if !matches!(remaining, [b'#', b'!', ..]) {
+            return false;
+        }
+
+        let mut end = 2;
+
+        while end < remaining.len() {
+            end += 1;
+
+            if remaining[end - 1] == b'\n' {
+                break;
+            }
+        }
+
+        // SAFETY: `end` is never greater than the length of the remaining region.
+        unsafe { self.advance_unchecked(end) };
+
+        true
+    }
+
+    /// Attempt to get the consumed region as a string.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(consumed)` if the consumed region is valid UTF-8.
+    ///
+    /// - `Err(error)` otherwise, which includes the case where the cursor lies within
+    ///   a character.
+    #[inline]
+    pub const fn consumed_str(&self) -> Result<&'a str, Utf8Error> {
+        str::from_utf8(self.consumed())
+    }
+
+    /// Attempt to get the remaining region as a string.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(remaining)` if the remaining region is valid UTF-8.
+    ///
+    /// - `Err(error)` otherwise, which includes the case where the cursor lies within
+    ///   a character.
+    #[inline]
+    pub const fn remaining_str(&self) -> Result<&'a str, Utf8Error> {
+        str::from_utf8(self.remaining())
+    }
+
+    /// Attempt to convert this byte slide into a string slide at the same offset.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slide)` if the entire source is valid UTF-8 and the cursor lies on a
+    ///   UTF-8 character boundary.
+    ///
+    /// - `Err(error)` if the source is not valid UTF-8, in which case `error` is relative
+    ///   to the start of the source.
+    ///
+    ///   If the source is valid UTF-8 but the cursor does not lie on a character boundary,
+    ///   then `error` is relative to the cursor instead.
+    #[inline]
+    pub const fn try_into_str_slide(self) -> Result<Slide<'a, str>, Utf8Error> {
+        let source = match str::from_utf8(self.source()) {
+            Ok(source) => source,
+            Err(error) => return Err(error),
+        };
+
+        let offset = self.offset();
+
+        if offset < source.len() && !is_utf8_char_boundary(source.as_bytes()[offset]) {
+            return match str::from_utf8(self.remaining()) {
+                Err(error) => Err(error),
+                // SAFETY: The remaining region starts with a UTF-8 continuation byte,
+                //         so it cannot be valid UTF-8.
+                Ok(..) => unsafe {
+                    unreachable_unchecked!("a string cannot start with a continuation byte")
+                },
+            };
+        }
+
+        // SAFETY: We just checked that `offset` lies on a character boundary.
+        Ok(unsafe { Slide::with_offset_unchecked(source, offset) })
+    }
+}
+
+impl<'a> Slide<'a, str> {
+    /// Advance the cursor past `prefix` if the remaining region starts with it.
+    ///
+    /// An empty `prefix` always matches, without moving the cursor.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the remaining region started with `prefix`. If not, the cursor
+    /// is not moved.
+    #[inline]
+    pub fn advance_if_str(
+        &mut self,
+        prefix: &str,
+    ) -> bool {
+        if !self.remaining().starts_with(prefix) {
+            return false;
+        }
+
+        // SAFETY: We just checked that the remaining region starts with `prefix`, which
+        //         being a `str` itself, ends on a char boundary.
+        unsafe { self.advance_unchecked(prefix.len()) };
+
+        true
+    }
+
+    /// Advance the cursor for as long as the next remaining [`char`] matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn advance_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let remaining = self.remaining();
+        let amount = remaining
+            .char_indices()
+            .find(|&(_, ch)| !pred(ch))
+            .map_or(remaining.len(), |(index, _)| index);
+
+        // SAFETY: `amount` is the start of a `char` within the remaining region, or its end.
+        unsafe { self.advance_unchecked(amount) }
+    }
+
+    /// Rewind the cursor for as long as the last consumed [`char`] matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn rewind_while<P>(
+        &mut self,
+        mut pred: P,
+    ) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let consumed = self.consumed();
+        let amount = consumed
+            .char_indices()
+            .rev()
+            .find(|&(_, ch)| !pred(ch))
+            .map_or(consumed.len(), |(index, ch)| {
+                consumed.len() - index - ch.len_utf8()
+            });
+
+        // SAFETY: `amount` is measured from the end of the consumed region to the end of a
+        //         `char` within it, or its start.
+        unsafe { self.rewind_unchecked(amount) }
+    }
+
+    /// Advance the cursor to the first remaining [`char`] that matches `pred`, leaving the
+    /// cursor *on* the match rather than past it.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the end.
+    #[inline]
+    pub fn find<P>(
+        &mut self,
+        mut pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(char) -> bool,
+    {
+        let remaining = self.remaining();
+        let found = remaining
+            .char_indices()
+            .find(|&(_, ch)| pred(ch))
+            .map(|(index, _)| index);
+        let amount = found.unwrap_or(remaining.len());
+
+        // SAFETY: `amount` is the start of a `char` within the remaining region, or its end.
+        unsafe { self.advance_unchecked(amount) };
+
+        found
+    }
+
+    /// Rewind the cursor to just after the last consumed [`char`] that matches `pred`, so
+    /// that the match is the last [`char`] of the consumed region.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(skipped)` with the amount of bytes skipped over if there was a match.
+    ///
+    /// - `None` if nothing matched, in which case the cursor is moved to the start.
+    #[inline]
+    pub fn rfind<P>(
+        &mut self,
+        mut pred: P,
+    ) -> Option<usize>
+    where
+        P: FnMut(char) -> bool,
+    {
+        let consumed = self.consumed();
+        let found = consumed
+            .char_indices()
+            .rev()
+            .find(|&(_, ch)| pred(ch))
+            .map(|(index, ch)| consumed.len() - index - ch.len_utf8());
+        let amount = found.unwrap_or(consumed.len());
+
+        // SAFETY: `amount` is measured from the end of the consumed region to the end of a
+        //         `char` within it, or its start.
+        unsafe { self.rewind_unchecked(amount) };
+
+        found
+    }
+
+    /// Convert this string slide into a byte slide at the same offset.
+    #[inline]
+    #[must_use]
+    pub const fn into_byte_slide(self) -> Slide<'a, [u8]> {
+        // SAFETY: Every character boundary is a valid split boundary for bytes.
+        unsafe { Slide::with_offset_unchecked(self.source().as_bytes(), self.offset()) }
+    }
+
+    /// Split the remaining region at `remaining_len * numer / denom`, rounded *down* to the
+    /// nearest character boundary, returning a new slide over each half.
+    ///
+    /// The split index is clamped to the length of the remaining region. If `denom == 0`,
+    /// then the split occurs at the end of the remaining region.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(head, tail)`, where both slides have their cursor at the start.
+    #[inline]
+    #[must_use]
+    pub const fn split_at_ratio(
+        &self,
+        numer: usize,
+        denom: usize,
+    ) -> (Slide<'a, str>, Slide<'a, str>) {
+        let mid = ratio_index(self.raw.remaining_len(), numer, denom);
+        let (head, tail) = self.split_at_floor(mid);
+
+        (Slide::new(head), Slide::new(tail))
+    }
+
+    /// Split the remaining region at byte `mid`, rounding `mid` *down* to the nearest
+    /// character boundary.
+    ///
+    /// This never fails. If `mid` is greater than the length of the remaining region, the
+    /// split occurs at the end.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(head, tail)`.
+    #[inline]
+    #[must_use]
+    pub const fn split_at_floor(
+        &self,
+        mid: usize,
+    ) -> (&'a str, &'a str) {
+        let remaining = self.remaining();
+        let mid = floor_char_boundary(remaining, mid);
+
+        // SAFETY: `floor_char_boundary` always returns a character boundary that is in bounds.
+        unsafe { split_at_unchecked(remaining, mid) }
+    }
+
+    /// Split the remaining region at byte `mid`, rounding `mid` *up* to the nearest
+    /// character boundary.
+    ///
+    /// This never fails. If `mid` is greater than the length of the remaining region, the
+    /// split occurs at the end.
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of `(head, tail)`.
+    #[inline]
+    #[must_use]
+    pub const fn split_at_ceil(
+        &self,
+        mid: usize,
+    ) -> (&'a str, &'a str) {
+        let remaining = self.remaining();
+        let mid = ceil_char_boundary(remaining, mid);
+
+        // SAFETY: `ceil_char_boundary` always returns a character boundary that is in bounds.
+        unsafe { split_at_unchecked(remaining, mid) }
+    }
+}
+
+/// Computes `len * numer / denom` clamped to `len`, treating `denom == 0` as an infinite ratio.
+#[inline]
+#[must_use]
+const fn ratio_index(
+    len: usize,
+    numer: usize,
+    denom: usize,
+) -> usize {
+    if denom == 0 {
+        return len;
+    }
+
+    // NOTE: We widen in order to avoid overflow in the intermediate product.
+    let index = (len as u128 * numer as u128) / denom as u128;
+
+    if index > len as u128 {
+        len
+    } else {
+        index as usize
+    }
+}
+
+impl<'a, S> Clone for Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, S> Copy for Slide<'a, S> where S: Slice + ?Sized {}
+
+impl<'a, S> fmt::Debug for Slide<'a, S>
+where
+    S: Slice + fmt::Debug + ?Sized,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let (consumed, remaining) = self.split();
+
+        f.debug_struct("Slide")
+            .field("consumed", &consumed)
+            .field("remaining", &remaining)
+            .finish()
+    }
+}
+
+impl<'a, S> From<&'a S> for Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    #[inline(always)]
+    fn from(slice: &'a S) -> Self {
+        Slide::new(slice)
+    }
+}
+
+// SAFETY: A `Slide<'a, S>` is semantically a `&'a S`.
+unsafe impl<'a, S> Send for Slide<'a, S> where S: Slice + Sync + ?Sized {}
+
+// SAFETY: A `Slide<'a, S>` is semantically a `&'a S`.
+unsafe impl<'a, S> Sync for Slide<'a, S> where S: Slice + Sync + ?Sized {}
@@ -0,0 +1,247 @@
+use crate::{slice::SplitError, slide::Slide};
+
+/// A [`Slide`] over a [`prim@str`] with conveniences for [`char`]-aware parsing.
+///
+/// Every movement keeps the cursor on a character boundary, so callers rarely need to
+/// reason about byte offsets.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct SlideStr<'a> {
+    /// The underlying slide.
+    slide: Slide<'a, str>,
+}
+
+impl<'a> SlideStr<'a> {
+    /// Create a new [`SlideStr`] with its cursor at the start of `s`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(s: &'a str) -> SlideStr<'a> {
+        SlideStr {
+            slide: Slide::new(s),
+        }
+    }
+
+    /// Create a new [`SlideStr`] from an existing string slide.
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_slide(slide: Slide<'a, str>) -> SlideStr<'a> {
+        SlideStr { slide }
+    }
+
+    /// Returns the underlying string slide.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slide(&self) -> &Slide<'a, str> {
+        &self.slide
+    }
+
+    /// Returns the underlying string slide, mutably.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slide_mut(&mut self) -> &mut Slide<'a, str> {
+        &mut self.slide
+    }
+
+    /// Convert this into the underlying string slide.
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_slide(self) -> Slide<'a, str> {
+        self.slide
+    }
+
+    /// Returns the offset of the cursor, in bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.slide.offset()
+    }
+
+    /// Returns whether the cursor is at the end of the source.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_at_end(&self) -> bool {
+        self.slide.is_at_end()
+    }
+
+    /// Returns the entire source string.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source(&self) -> &'a str {
+        self.slide.source()
+    }
+
+    /// Returns the string before the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn consumed(&self) -> &'a str {
+        self.slide.consumed()
+    }
+
+    /// Returns the string after the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remaining(&self) -> &'a str {
+        self.slide.remaining()
+    }
+
+    /// Returns the next [`char`] without moving the cursor.
+    #[inline]
+    #[must_use]
+    pub fn peek_char(&self) -> Option<char> {
+        self.remaining().chars().next()
+    }
+
+    /// Advance the cursor past the next [`char`].
+    ///
+    /// # Returns
+    ///
+    /// - `Some(ch)` upon success.
+    ///
+    /// - `None` if the cursor is at the end.
+    #[inline]
+    pub fn advance_char(&mut self) -> Option<char> {
+        self.slide.iter_forward().next()
+    }
+
+    /// Advance the cursor past the next [`char`] if it matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(ch)` if it matched.
+    ///
+    /// - `None` if it did not, or if the cursor is at the end.
+    #[inline]
+    pub fn advance_char_if<P>(
+        &mut self,
+        pred: P,
+    ) -> Option<char>
+    where
+        P: FnOnce(char) -> bool,
+    {
+        match self.peek_char() {
+            Some(ch) if pred(ch) => self.advance_char(),
+            _ => None,
+        }
+    }
+
+    /// Returns the next line without moving the cursor.
+    ///
+    /// See [`SlideStr::next_line`] for details.
+    #[inline]
+    #[must_use]
+    pub fn peek_line(&self) -> Option<&'a str> {
+        let (line, _) = self.split_line()?;
+
+        Some(line)
+    }
+
+    /// Advance the cursor past the next line and its terminator.
+    ///
+    /// Lines are terminated by either `"\n"` or `"\r\n"`, and the terminator is not
+    /// included in the returned line. The final line need not be terminated.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(line)` upon success, which is empty for an empty line.
+    ///
+    /// - `None` if the cursor is at the end.
+    #[inline]
+    pub fn next_line(&mut self) -> Option<&'a str> {
+        let (line, amount) = self.split_line()?;
+
+        // SAFETY: `amount` is either the end of the remaining string, or just past a `'\n'`,
+        //         both of which lie on a character boundary.
+        unsafe { self.slide.advance_unchecked(amount) };
+
+        Some(line)
+    }
+
+    /// Returns the next line without its terminator, along with the amount of bytes
+    /// that it spans including its terminator.
+    #[inline]
+    fn split_line(&self) -> Option<(&'a str, usize)> {
+        let remaining = self.remaining();
+
+        if remaining.is_empty() {
+            return None;
+        }
+
+        match remaining.find('\n') {
+            Some(index) => {
+                let line = &remaining[..index];
+                let line = line.strip_suffix('\r').unwrap_or(line);
+
+                Some((line, index + 1))
+            }
+            None => Some((remaining, remaining.len())),
+        }
+    }
+
+    /// Try to advance the cursor by `amount` bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(region)` with the region moved over upon success.
+    ///
+    /// - `Err(SplitError::OutOfBounds { .. })` if `amount` exceeds the remaining length.
+    ///
+    /// - `Err(SplitError::Other(StrSplitError::InvalidCharBoundary { .. }))` if the cursor
+    ///   would not land on a character boundary.
+    #[inline]
+    pub const fn advance_bytes(
+        &mut self,
+        amount: usize,
+    ) -> Result<&'a str, SplitError<str>> {
+        self.slide.try_advance(amount)
+    }
+
+    /// Advance the cursor for as long as the next [`char`] matches `pred`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over, which is empty if nothing matched.
+    #[inline]
+    pub fn advance_while<P>(
+        &mut self,
+        pred: P,
+    ) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        self.slide.advance_while(pred)
+    }
+
+    /// Advance the cursor past `prefix` if the remaining string starts with it.
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the cursor moved past `prefix`.
+    #[inline]
+    pub fn advance_if_str(
+        &mut self,
+        prefix: &str,
+    ) -> bool {
+        self.slide.advance_if_str(prefix)
+    }
+}
+
+impl<'a> From<&'a str> for SlideStr<'a> {
+    #[inline(always)]
+    fn from(s: &'a str) -> Self {
+        SlideStr::new(s)
+    }
+}
+
+impl<'a> From<Slide<'a, str>> for SlideStr<'a> {
+    #[inline(always)]
+    fn from(slide: Slide<'a, str>) -> Self {
+        SlideStr::from_slide(slide)
+    }
+}
+
+impl<'a> From<SlideStr<'a>> for Slide<'a, str> {
+    #[inline(always)]
+    fn from(slide: SlideStr<'a>) -> Self {
+        slide.slide
+    }
+}
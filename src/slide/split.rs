@@ -0,0 +1,1002 @@
+use core::{fmt, iter::FusedIterator};
+
+use crate::{
+    slice::{Slice, is_empty, len, split_at_unchecked},
+    slide::{Slide, SlideMut, private::Sealed},
+};
+
+/// A delimiter that can be searched for within some `S`.
+///
+/// # Safety
+///
+/// Any match returned by [`Delimiter::find_in`] *must* start and end on valid split
+/// boundaries of the haystack, with the start being less than or equal to the end.
+pub unsafe trait Delimiter<S>: Sealed<S>
+where
+    S: Slice + ?Sized,
+{
+    /// Find the first occurrence of this delimiter within `haystack`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((start, end))` if an occurrence was found, where `start..end` is the
+    ///   range of the occurrence within `haystack`.
+    ///
+    /// - `None` if there are no occurrences.
+    #[doc(hidden)]
+    fn find_in(
+        &self,
+        haystack: &S,
+    ) -> Option<(usize, usize)>;
+
+    /// Find the last occurrence of this delimiter within `haystack`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((start, end))` if an occurrence was found, where `start..end` is the
+    ///   range of the occurrence within `haystack`.
+    ///
+    /// - `None` if there are no occurrences.
+    #[doc(hidden)]
+    fn rfind_in(
+        &self,
+        haystack: &S,
+    ) -> Option<(usize, usize)>;
+}
+
+impl<T> Sealed<[T]> for T where T: PartialEq {}
+
+// SAFETY: Any index within the length of a `[T]` is a valid split boundary.
+unsafe impl<T> Delimiter<[T]> for T
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn find_in(
+        &self,
+        haystack: &[T],
+    ) -> Option<(usize, usize)> {
+        let start = haystack.iter().position(|elem| elem == self)?;
+
+        Some((start, start + 1))
+    }
+
+    #[inline]
+    fn rfind_in(
+        &self,
+        haystack: &[T],
+    ) -> Option<(usize, usize)> {
+        let start = haystack.iter().rposition(|elem| elem == self)?;
+
+        Some((start, start + 1))
+    }
+}
+
+impl<'b, T> Sealed<[T]> for &'b [T] where T: PartialEq {}
+
+// SAFETY: Any index within the length of a `[T]` is a valid split boundary.
+unsafe impl<'b, T> Delimiter<[T]> for &'b [T]
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn find_in(
+        &self,
+        haystack: &[T],
+    ) -> Option<(usize, usize)> {
+        // NOTE: An empty delimiter never matches, as otherwise it'd match everywhere.
+        if self.is_empty() {
+            return None;
+        }
+
+        let start = haystack
+            .windows(self.len())
+            .position(|window| window == *self)?;
+
+        Some((start, start + self.len()))
+    }
+
+    #[inline]
+    fn rfind_in(
+        &self,
+        haystack: &[T],
+    ) -> Option<(usize, usize)> {
+        // NOTE: An empty delimiter never matches, as otherwise it'd match everywhere.
+        if self.is_empty() {
+            return None;
+        }
+
+        let start = haystack
+            .windows(self.len())
+            .rposition(|window| window == *self)?;
+
+        Some((start, start + self.len()))
+    }
+}
+
+impl Sealed<str> for char {}
+
+// SAFETY: The start and end of any `char` within a `str` lie on char boundaries.
+unsafe impl Delimiter<str> for char {
+    #[inline]
+    fn find_in(
+        &self,
+        haystack: &str,
+    ) -> Option<(usize, usize)> {
+        let start = haystack.find(*self)?;
+
+        Some((start, start + self.len_utf8()))
+    }
+
+    #[inline]
+    fn rfind_in(
+        &self,
+        haystack: &str,
+    ) -> Option<(usize, usize)> {
+        let start = haystack.rfind(*self)?;
+
+        Some((start, start + self.len_utf8()))
+    }
+}
+
+impl<'b> Sealed<str> for &'b str {}
+
+// SAFETY: The start and end of any `str` within a `str` lie on char boundaries.
+unsafe impl<'b> Delimiter<str> for &'b str {
+    #[inline]
+    fn find_in(
+        &self,
+        haystack: &str,
+    ) -> Option<(usize, usize)> {
+        // NOTE: An empty delimiter never matches, as otherwise it'd match everywhere.
+        if self.is_empty() {
+            return None;
+        }
+
+        let start = haystack.find(*self)?;
+
+        Some((start, start + self.len()))
+    }
+
+    #[inline]
+    fn rfind_in(
+        &self,
+        haystack: &str,
+    ) -> Option<(usize, usize)> {
+        // NOTE: An empty delimiter never matches, as otherwise it'd match everywhere.
+        if self.is_empty() {
+            return None;
+        }
+
+        let start = haystack.rfind(*self)?;
+
+        Some((start, start + self.len()))
+    }
+}
+
+/// An iterator over the segments of a [`Slide`] that are separated by some delimiter.
+///
+/// Each segment excludes the delimiter, and the slide is advanced past every
+/// delimiter as it is found.
+///
+/// See [`Slide::split_on`], [`Slide::split_on_slice`] and [`Slide::split_on_char`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitOn<'a, S, D>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're splitting.
+    slide: Slide<'a, S>,
+    /// The delimiter we're splitting on.
+    delim: D,
+    /// The maximum amount of segments we can still yield.
+    limit: usize,
+}
+
+impl<'a, S, D> SplitOn<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+    /// Create a new [`SplitOn`] yielding at most `limit` segments.
+    #[inline(always)]
+    pub(crate) const fn new(
+        slide: Slide<'a, S>,
+        delim: D,
+        limit: usize,
+    ) -> SplitOn<'a, S, D> {
+        SplitOn {
+            slide,
+            delim,
+            limit,
+        }
+    }
+
+    /// Returns the underlying slide, positioned after the last delimiter found.
+    #[inline(always)]
+    #[must_use]
+    pub const fn slide(&self) -> Slide<'a, S> {
+        self.slide
+    }
+
+    /// Returns the part of the source that has yet to be yielded, if any.
+    #[inline]
+    #[must_use]
+    pub const fn remainder(&self) -> Option<&'a S> {
+        if self.limit > 0 {
+            Some(self.slide.remaining())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, S, D> Iterator for SplitOn<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+    type Item = &'a S;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a S> {
+        let remaining = self.slide.remaining();
+
+        let found = match self.limit {
+            0 => return None,
+            1 => None,
+            _ => self.delim.find_in(remaining),
+        };
+
+        self.limit -= 1;
+
+        match found {
+            Some((start, end)) => {
+                // SAFETY: The delimiter ensures that `start` lies on a valid split boundary
+                //         within the remaining region.
+                let segment = unsafe { self.slide.advance_unchecked(start) };
+
+                // SAFETY: The delimiter ensures that `end` lies on a valid split boundary
+                //         within the remaining region, at or after `start`.
+                unsafe { self.slide.advance_unchecked(end - start) };
+
+                Some(segment)
+            }
+            None => {
+                // NOTE: Nothing can be yielded after the final segment.
+                self.limit = 0;
+
+                // SAFETY: It is always valid to split at the end of a slice.
+                Some(unsafe { self.slide.advance_unchecked(len(remaining)) })
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.limit {
+            0 => (0, Some(0)),
+            limit => (
+                1,
+                Some(limit.min(len(self.slide.remaining()).saturating_add(1))),
+            ),
+        }
+    }
+}
+
+impl<'a, S, D> Clone for SplitOn<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        SplitOn {
+            slide: self.slide,
+            delim: self.delim.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+impl<'a, S, D> fmt::Debug for SplitOn<'a, S, D>
+where
+    S: Slice + fmt::Debug + ?Sized,
+    D: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("SplitOn")
+            .field("slide", &self.slide)
+            .field("delim", &self.delim)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<'a, S, D> FusedIterator for SplitOn<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+}
+
+/// An iterator over the segments of a [`Slide`] that are terminated by some delimiter.
+///
+/// Unlike [`SplitOn`], each segment includes its terminating delimiter. The final segment
+/// lacks one if the slide doesn't end with a delimiter, and an empty final segment is
+/// never yielded.
+///
+/// See [`Slide::split_inclusive_on`] and [`Slide::split_inclusive_on_char`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusive<'a, S, D>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're splitting.
+    slide: Slide<'a, S>,
+    /// The delimiter we're splitting on.
+    delim: D,
+}
+
+impl<'a, S, D> SplitInclusive<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+    /// Create a new [`SplitInclusive`].
+    #[inline(always)]
+    pub(crate) const fn new(
+        slide: Slide<'a, S>,
+        delim: D,
+    ) -> SplitInclusive<'a, S, D> {
+        SplitInclusive { slide, delim }
+    }
+
+    /// Returns the underlying slide, positioned after the last segment yielded.
+    #[inline(always)]
+    #[must_use]
+    pub const fn slide(&self) -> Slide<'a, S> {
+        self.slide
+    }
+
+    /// Returns the part of the source that has yet to be yielded.
+    #[inline(always)]
+    #[must_use]
+    pub const fn remainder(&self) -> &'a S {
+        self.slide.remaining()
+    }
+}
+
+impl<'a, S, D> Iterator for SplitInclusive<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+    type Item = &'a S;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a S> {
+        let remaining = self.slide.remaining();
+
+        if is_empty(remaining) {
+            return None;
+        }
+
+        let end = match self.delim.find_in(remaining) {
+            Some((_, end)) => end,
+            None => len(remaining),
+        };
+
+        // SAFETY: The delimiter ensures that `end` lies on a valid split boundary within the
+        //         remaining region, and it is always valid to split at the end of a slice.
+        Some(unsafe { self.slide.advance_unchecked(end) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match len(self.slide.remaining()) {
+            0 => (0, Some(0)),
+            len => (1, Some(len)),
+        }
+    }
+}
+
+impl<'a, S, D> Clone for SplitInclusive<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        SplitInclusive {
+            slide: self.slide,
+            delim: self.delim.clone(),
+        }
+    }
+}
+
+impl<'a, S, D> fmt::Debug for SplitInclusive<'a, S, D>
+where
+    S: Slice + fmt::Debug + ?Sized,
+    D: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("slide", &self.slide)
+            .field("delim", &self.delim)
+            .finish()
+    }
+}
+
+impl<'a, S, D> FusedIterator for SplitInclusive<'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+}
+
+/// An iterator over the segments of a borrowed [`Slide`] that are separated by some
+/// delimiter.
+///
+/// Each segment excludes the delimiter, and the empty segment between two adjacent
+/// delimiters is yielded as an empty segment. The trailing segment is always yielded last.
+///
+/// After each segment is yielded, the cursor of the slide lies just past the delimiter
+/// that terminated it, or at the end once the trailing segment has been yielded. So if
+/// the iterator is dropped early, the remaining region of the slide starts with the next
+/// segment.
+///
+/// See [`Slide::splits`] and [`Slide::splits_char`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Splits<'s, 'a, S, D>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're splitting.
+    slide: &'s mut Slide<'a, S>,
+    /// The delimiter we're splitting on.
+    delim: D,
+    /// Whether the trailing segment has been yielded.
+    finished: bool,
+}
+
+impl<'s, 'a, S, D> Iterator for Splits<'s, 'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+    type Item = &'a S;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a S> {
+        if self.finished {
+            return None;
+        }
+
+        let remaining = self.slide.remaining();
+
+        match self.delim.find_in(remaining) {
+            Some((start, end)) => {
+                // SAFETY: The delimiter ensures that `start` lies on a valid split boundary
+                //         within the remaining region.
+                let segment = unsafe { self.slide.advance_unchecked(start) };
+
+                // SAFETY: The delimiter ensures that `end` lies on a valid split boundary
+                //         within the remaining region, at or after `start`.
+                unsafe { self.slide.advance_unchecked(end - start) };
+
+                Some(segment)
+            }
+            None => {
+                self.finished = true;
+
+                // SAFETY: It is always valid to split at the end of a slice.
+                Some(unsafe { self.slide.advance_unchecked(len(remaining)) })
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, len(self.slide.remaining()).checked_add(1))
+        }
+    }
+}
+
+impl<'s, 'a, S, D> fmt::Debug for Splits<'s, 'a, S, D>
+where
+    S: Slice + fmt::Debug + ?Sized,
+    D: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("Splits")
+            .field("slide", &self.slide)
+            .field("delim", &self.delim)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'s, 'a, S, D> FusedIterator for Splits<'s, 'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+}
+
+/// An iterator over the segments of a borrowed [`SlideMut`] that are separated by some
+/// delimiter, yielding each segment mutably.
+///
+/// This behaves exactly like [`Splits`], including where the cursor of the slide lies
+/// if the iterator is dropped early.
+///
+/// See [`SlideMut::splits`] and [`SlideMut::splits_char`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitsMut<'s, 'a, S, D>
+where
+    S: Slice + ?Sized,
+{
+    /// The slide we're splitting.
+    slide: &'s mut SlideMut<'a, S>,
+    /// The delimiter we're splitting on.
+    delim: D,
+    /// Whether the trailing segment has been yielded.
+    finished: bool,
+}
+
+impl<'s, 'a, S, D> Iterator for SplitsMut<'s, 'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+    type Item = &'s mut S;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'s mut S> {
+        if self.finished {
+            return None;
+        }
+
+        let remaining = self.slide.remaining();
+
+        let segment: *mut S = match self.delim.find_in(remaining) {
+            Some((start, end)) => {
+                // SAFETY: The delimiter ensures that `start` lies on a valid split boundary
+                //         within the remaining region.
+                let segment = unsafe { self.slide.advance_unchecked(start) } as *mut S;
+
+                // SAFETY: The delimiter ensures that `end` lies on a valid split boundary
+                //         within the remaining region, at or after `start`.
+                unsafe { self.slide.advance_unchecked(end - start) };
+
+                segment
+            }
+            None => {
+                self.finished = true;
+
+                let amount = len(remaining);
+
+                // SAFETY: It is always valid to split at the end of a slice.
+                unsafe { self.slide.advance_unchecked(amount) }
+            }
+        };
+
+        // SAFETY: The segment now lies within the consumed region, which the slide cannot
+        //         touch for as long as it is mutably borrowed by us. Every segment we yield
+        //         is disjoint, as the cursor only ever moves forward past them.
+        Some(unsafe { &mut *segment })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, len(self.slide.remaining()).checked_add(1))
+        }
+    }
+}
+
+impl<'s, 'a, S, D> fmt::Debug for SplitsMut<'s, 'a, S, D>
+where
+    S: Slice + fmt::Debug + ?Sized,
+    D: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        // NOTE: The consumed region holds the segments we have already yielded, which may
+        //       still be borrowed mutably, so we must not read it.
+        f.debug_struct("SplitsMut")
+            .field("remaining", &self.slide.remaining())
+            .field("delim", &self.delim)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'s, 'a, S, D> FusedIterator for SplitsMut<'s, 'a, S, D>
+where
+    S: Slice + ?Sized,
+    D: Delimiter<S>,
+{
+}
+
+impl<'a, S> Slide<'a, S>
+where
+    S: Slice + ?Sized,
+{
+    /// Split the remaining region around the first occurrence of `delim`, without moving
+    /// the cursor.
+    ///
+    /// This mirrors [`str::split_once`].
+    ///
+    /// # Returns
+    ///
+    /// - `Some((head, tail))` if `delim` was found, where `head` precedes the delimiter
+    ///   and `tail` follows it.
+    ///
+    /// - `None` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn split_once<D>(
+        &self,
+        delim: D,
+    ) -> Option<(&'a S, &'a S)>
+    where
+        D: Delimiter<S>,
+    {
+        let mut slide = *self;
+        let head = slide.advance_past_once(delim)?;
+
+        Some((head, slide.remaining()))
+    }
+
+    /// Split the remaining region around the last occurrence of `delim`, without moving
+    /// the cursor.
+    ///
+    /// This mirrors [`str::rsplit_once`].
+    ///
+    /// # Returns
+    ///
+    /// - `Some((head, tail))` if `delim` was found, where `head` precedes the delimiter
+    ///   and `tail` follows it.
+    ///
+    /// - `None` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn rsplit_once<D>(
+        &self,
+        delim: D,
+    ) -> Option<(&'a S, &'a S)>
+    where
+        D: Delimiter<S>,
+    {
+        let remaining = self.remaining();
+        let (start, end) = delim.rfind_in(remaining)?;
+
+        // SAFETY: The delimiter ensures that `end` lies on a valid split boundary within
+        //         the remaining region.
+        let (rest, tail) = unsafe { split_at_unchecked(remaining, end) };
+
+        // SAFETY: The delimiter ensures that `start` lies on a valid split boundary within
+        //         the remaining region, at or before `end`.
+        let (head, _) = unsafe { split_at_unchecked(rest, start) };
+
+        Some((head, tail))
+    }
+
+    /// Advance the cursor past the first occurrence of `delim`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(head)` if `delim` was found, where `head` is the region preceding the
+    ///   delimiter.
+    ///
+    /// - `None` otherwise, in which case the cursor is not moved.
+    #[inline]
+    pub fn advance_past_once<D>(
+        &mut self,
+        delim: D,
+    ) -> Option<&'a S>
+    where
+        D: Delimiter<S>,
+    {
+        let (start, end) = delim.find_in(self.remaining())?;
+
+        // SAFETY: The delimiter ensures that `start` lies on a valid split boundary within
+        //         the remaining region.
+        let head = unsafe { self.advance_unchecked(start) };
+
+        // SAFETY: The delimiter ensures that `end` lies on a valid split boundary within
+        //         the remaining region, at or after `start`.
+        unsafe { self.advance_unchecked(end - start) };
+
+        Some(head)
+    }
+}
+
+impl<'a, T> Slide<'a, [T]>
+where
+    T: PartialEq,
+{
+    /// Returns an iterator over the segments of the remaining region that are
+    /// separated by `delim`, advancing this slide past every `delim` found.
+    ///
+    /// Each segment excludes the delimiter.
+    #[inline]
+    pub const fn split_on(
+        self,
+        delim: T,
+    ) -> SplitOn<'a, [T], T> {
+        SplitOn::new(self, delim, usize::MAX)
+    }
+
+    /// Returns an iterator over the segments of the remaining region that are
+    /// separated by `delim`, yielding at most `limit` segments.
+    ///
+    /// The last segment contains the rest of the remaining region.
+    #[inline]
+    pub const fn splitn_on(
+        self,
+        limit: usize,
+        delim: T,
+    ) -> SplitOn<'a, [T], T> {
+        SplitOn::new(self, delim, limit)
+    }
+
+    /// Returns an iterator over the segments of the remaining region that are
+    /// separated by the elements of `delim`, advancing this slide past every `delim` found.
+    ///
+    /// Each segment excludes the delimiter. An empty `delim` never matches.
+    #[inline]
+    pub const fn split_on_slice<'b>(
+        self,
+        delim: &'b [T],
+    ) -> SplitOn<'a, [T], &'b [T]> {
+        SplitOn::new(self, delim, usize::MAX)
+    }
+
+    /// Returns an iterator over the segments of the remaining region that are
+    /// separated by the elements of `delim`, yielding at most `limit` segments.
+    ///
+    /// The last segment contains the rest of the remaining region. An empty `delim`
+    /// never matches.
+    #[inline]
+    pub const fn splitn_on_slice<'b>(
+        self,
+        limit: usize,
+        delim: &'b [T],
+    ) -> SplitOn<'a, [T], &'b [T]> {
+        SplitOn::new(self, delim, limit)
+    }
+
+    /// Returns an iterator over the segments of the remaining region that are
+    /// terminated by `delim`, advancing this slide past every segment yielded.
+    ///
+    /// Each segment includes its terminating delimiter, except for possibly the last.
+    #[inline]
+    pub const fn split_inclusive_on(
+        self,
+        delim: T,
+    ) -> SplitInclusive<'a, [T], T> {
+        SplitInclusive::new(self, delim)
+    }
+}
+
+impl<'a> Slide<'a, str> {
+    /// Returns an iterator over the segments of the remaining region that are
+    /// separated by `delim`, advancing this slide past every `delim` found.
+    ///
+    /// Each segment excludes the delimiter.
+    #[inline]
+    pub const fn split_on_char(
+        self,
+        delim: char,
+    ) -> SplitOn<'a, str, char> {
+        SplitOn::new(self, delim, usize::MAX)
+    }
+
+    /// Returns an iterator over the segments of the remaining region that are
+    /// separated by `delim`, yielding at most `limit` segments.
+    ///
+    /// The last segment contains the rest of the remaining region.
+    #[inline]
+    pub const fn splitn_on_char(
+        self,
+        limit: usize,
+        delim: char,
+    ) -> SplitOn<'a, str, char> {
+        SplitOn::new(self, delim, limit)
+    }
+
+    /// Returns an iterator over the segments of the remaining region that are
+    /// terminated by `delim`, advancing this slide past every segment yielded.
+    ///
+    /// Each segment includes its terminating delimiter, except for possibly the last.
+    #[inline]
+    pub const fn split_inclusive_on_char(
+        self,
+        delim: char,
+    ) -> SplitInclusive<'a, str, char> {
+        SplitInclusive::new(self, delim)
+    }
+}
+
+impl<'a, T> Slide<'a, [T]>
+where
+    T: PartialEq,
+{
+    /// Returns an iterator over the segments of the remaining region that are separated
+    /// by `delim`, advancing this slide past every `delim` found.
+    ///
+    /// Unlike [`Slide::split_on`], this borrows the slide. See [`Splits`] for where the
+    /// cursor lies if the iterator is dropped early.
+    #[inline]
+    pub const fn splits(
+        &mut self,
+        delim: T,
+    ) -> Splits<'_, 'a, [T], T> {
+        Splits {
+            slide: self,
+            delim,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> Slide<'a, str> {
+    /// Returns an iterator over the segments of the remaining region that are separated
+    /// by `delim`, advancing this slide past every `delim` found.
+    ///
+    /// Unlike [`Slide::split_on_char`], this borrows the slide. See [`Splits`] for where
+    /// the cursor lies if the iterator is dropped early.
+    #[inline]
+    pub const fn splits_char(
+        &mut self,
+        delim: char,
+    ) -> Splits<'_, 'a, str, char> {
+        Splits {
+            slide: self,
+            delim,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, T> SlideMut<'a, [T]>
+where
+    T: PartialEq,
+{
+    /// Returns an iterator over the segments of the remaining region that are separated
+    /// by `delim`, advancing this slide past every `delim` found.
+    ///
+    /// See [`SplitsMut`] for where the cursor lies if the iterator is dropped early.
+    #[inline]
+    pub const fn splits(
+        &mut self,
+        delim: T,
+    ) -> SplitsMut<'_, 'a, [T], T> {
+        SplitsMut {
+            slide: self,
+            delim,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> SlideMut<'a, str> {
+    /// Returns an iterator over the segments of the remaining region that are separated
+    /// by `delim`, advancing this slide past every `delim` found.
+    ///
+    /// See [`SplitsMut`] for where the cursor lies if the iterator is dropped early.
+    #[inline]
+    pub const fn splits_char(
+        &mut self,
+        delim: char,
+    ) -> SplitsMut<'_, 'a, str, char> {
+        SplitsMut {
+            slide: self,
+            delim,
+            finished: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_yields_every_segment() {
+        let mut slide = Slide::new(&b"a,,bc,"[..]);
+        let segments: Vec<&[u8]> = slide.splits(b',').collect();
+
+        assert_eq!(segments, [&b"a"[..], b"", b"bc", b""]);
+        assert!(slide.is_at_end());
+    }
+
+    #[test]
+    fn splits_char_yields_every_segment() {
+        let mut slide = Slide::new("αβγ");
+        let segments: Vec<&str> = slide.splits_char('β').collect();
+
+        assert_eq!(segments, ["α", "γ"]);
+        assert!(slide.is_at_end());
+    }
+
+    #[test]
+    fn splits_dropped_early() {
+        let mut slide = Slide::new(&b"ab,cd,ef"[..]);
+
+        assert_eq!(slide.splits(b',').next(), Some(&b"ab"[..]));
+        assert_eq!(slide.offset(), 3);
+        assert_eq!(slide.remaining(), b"cd,ef");
+
+        let mut splits = slide.splits(b',');
+        assert_eq!(splits.next(), Some(&b"cd"[..]));
+        assert_eq!(splits.next(), Some(&b"ef"[..]));
+        assert_eq!(splits.next(), None);
+        assert!(slide.is_at_end());
+    }
+
+    #[test]
+    fn splits_mut_yields_disjoint_segments() {
+        let mut buf = *b"ab,cd,ef";
+        let mut slide = SlideMut::new(&mut buf[..]);
+        let segments: Vec<&mut [u8]> = slide.splits(b',').collect();
+
+        for segment in segments {
+            segment.make_ascii_uppercase();
+        }
+
+        assert!(slide.is_at_end());
+        assert_eq!(buf, *b"AB,CD,EF");
+    }
+
+    #[test]
+    fn splits_mut_dropped_early() {
+        let mut buf = *b"ab,cd,ef";
+        let mut slide = SlideMut::new(&mut buf[..]);
+
+        slide.splits(b',').next().unwrap().make_ascii_uppercase();
+
+        assert_eq!(slide.offset(), 3);
+        assert_eq!(slide.remaining(), b"cd,ef");
+        assert_eq!(buf, *b"AB,cd,ef");
+    }
+
+    #[test]
+    fn splits_mut_debug_only_shows_remaining() {
+        let mut buf = *b"ab,cd";
+        let mut slide = SlideMut::new(&mut buf[..]);
+        let mut splits = slide.splits(b',');
+        let first = splits.next().unwrap();
+
+        assert_eq!(
+            format!("{splits:?}"),
+            "SplitsMut { remaining: [99, 100], delim: 44, finished: false }"
+        );
+
+        first[0] = b'x';
+        splits.next();
+
+        assert_eq!(
+            format!("{splits:?}"),
+            "SplitsMut { remaining: [], delim: 44, finished: true }"
+        );
+    }
+}
@@ -0,0 +1,491 @@
+use core::{error::Error, fmt, mem::MaybeUninit, ptr, slice};
+
+use crate::{
+    slice::SplitError,
+    slide::{Slide, SlideMut},
+};
+
+/// A fixed-capacity, stack allocated buffer with a built-in cursor.
+///
+/// A [`StackSlide`] can be filled with elements via [`StackSlide::push`] and
+/// [`StackSlide::extend_from_slice`], and then consumed with the same cursor semantics
+/// as a [`Slide`] over the initialized elements.
+///
+/// It is intended for fixed-size scratch buffers where heap allocation is unavailable.
+pub struct StackSlide<T, const N: usize> {
+    /// The backing buffer.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` elements must be initialized.
+    buf: [MaybeUninit<T>; N],
+    /// The amount of initialized elements.
+    ///
+    /// # Safety
+    ///
+    /// This must be less than or equal to `N`.
+    len: usize,
+    /// The offset of the cursor.
+    ///
+    /// # Safety
+    ///
+    /// This must be less than or equal to `len`.
+    offset: usize,
+}
+
+impl<T, const N: usize> StackSlide<T, N> {
+    /// Create a new, empty [`StackSlide`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> StackSlide<T, N> {
+        StackSlide {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+            offset: 0,
+        }
+    }
+
+    /// Returns the maximum amount of elements this [`StackSlide`] can hold.
+    #[inline(always)]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the amount of initialized elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether there are no initialized elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether there is no spare capacity left.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the amount of elements that can still be written.
+    #[inline(always)]
+    #[must_use]
+    pub const fn spare_capacity(&self) -> usize {
+        // SAFETY: `len` is always less than or equal to `N`.
+        unsafe { N.unchecked_sub(self.len) }
+    }
+
+    /// Returns the initialized elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the initialized elements, mutably.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Attempt to append `value` to the initialized elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` upon success.
+    ///
+    /// - `Err(error)` if there is no spare capacity left, where `error` holds `value`.
+    #[inline]
+    pub const fn push(
+        &mut self,
+        value: T,
+    ) -> Result<(), CapacityError<T>> {
+        if self.len < N {
+            self.buf[self.len].write(value);
+            self.len += 1;
+
+            Ok(())
+        } else {
+            Err(CapacityError::new(value))
+        }
+    }
+
+    /// Attempt to append clones of all elements in `other` to the initialized elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` upon success.
+    ///
+    /// - `Err(error)` if there is not enough spare capacity to hold `other`.
+    ///
+    ///   Nothing is written upon failure.
+    #[inline]
+    pub fn extend_from_slice(
+        &mut self,
+        other: &[T],
+    ) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        if other.len() > self.spare_capacity() {
+            return Err(CapacityError::new(()));
+        }
+
+        for elem in other {
+            self.buf[self.len].write(elem.clone());
+
+            // NOTE: We increment this per element so that if `clone` panics we
+            //       still drop everything we've written so far.
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Drop all initialized elements and reset the cursor.
+    #[inline]
+    pub fn clear(&mut self) {
+        let elems: *mut [T] = self.as_mut_slice();
+
+        // NOTE: We reset these first in case a destructor panics.
+        self.len = 0;
+        self.offset = 0;
+
+        // SAFETY: `elems` were all initialized, and are no longer considered to be.
+        unsafe { ptr::drop_in_place(elems) };
+    }
+
+    /// Returns a [`SlideMut`] over the initialized elements along with the cursor
+    /// of this [`StackSlide`], borrowed separately.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    fn slide_mut_and_cursor(&mut self) -> (SlideMut<'_, [T]>, &mut usize) {
+        // SAFETY: The first `len` elements are initialized.
+        let elems = unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len) };
+
+        // SAFETY: `offset` is always less than or equal to `len`.
+        let slide = unsafe { SlideMut::with_offset_unchecked(elems, self.offset) };
+
+        (slide, &mut self.offset)
+    }
+
+    /// Returns a [`Slide`] over the initialized elements, with its cursor at the
+    /// cursor of this [`StackSlide`].
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn as_slide(&self) -> Slide<'_, [T]> {
+        // SAFETY: `offset` is always less than or equal to `len`.
+        unsafe { Slide::with_offset_unchecked(self.as_slice(), self.offset) }
+    }
+
+    /// Run `f` with a [`SlideMut`] over the initialized elements, with its cursor
+    /// at the cursor of this [`StackSlide`].
+    ///
+    /// Any cursor movements made by `f` are written back to this [`StackSlide`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` replaces the slide with one over a different source.
+    ///
+    /// Moving the end of the slide toward its start, such as with
+    /// [`SlideMut::dedup_remaining`], is fine. Any elements past the new end remain in
+    /// this [`StackSlide`].
+    #[inline]
+    #[track_caller]
+    pub fn with_slide_mut<R, F>(
+        &mut self,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&mut SlideMut<'_, [T]>) -> R,
+    {
+        let len = self.len;
+        let (mut slide, cursor) = self.slide_mut_and_cursor();
+        let start = slide.source().as_ptr();
+        let result = f(&mut slide);
+
+        // NOTE: `f` is free to overwrite `slide` with a slide over some other source, whose
+        //       offset would break our invariant that `offset <= len`. Only the end of the
+        //       slide may legitimately move, and only ever toward the start.
+        assert!(
+            ptr::eq(slide.source().as_ptr(), start) && slide.source().len() <= len,
+            "the slide was replaced with one over a different source"
+        );
+        assert!(slide.offset() <= len);
+
+        *cursor = slide.offset();
+
+        result
+    }
+
+    /// Returns the offset of the cursor from the start of the initialized elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the consumed region, the region before the cursor.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn consumed(&self) -> &[T] {
+        self.as_slide().consumed()
+    }
+
+    /// Returns the remaining region, the region at or after the cursor.
+    #[inline(always)]
+    #[must_use]
+    #[track_caller]
+    pub const fn remaining(&self) -> &[T] {
+        self.as_slide().remaining()
+    }
+
+    /// Attempt to advance the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(advanced)` upon success, where `advanced` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if there are less than `amount` remaining elements.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub fn try_advance(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut [T], SplitError<[T]>> {
+        let (mut slide, cursor) = self.slide_mut_and_cursor();
+        let offset = slide.offset();
+
+        slide.try_advance(amount)?;
+        *cursor = slide.offset();
+
+        let (consumed, _) = slide.into_split();
+
+        Ok(&mut consumed[offset..])
+    }
+
+    /// Advance the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `amount` remaining elements.
+    #[inline]
+    #[track_caller]
+    pub fn advance(
+        &mut self,
+        amount: usize,
+    ) -> &mut [T] {
+        let (mut slide, cursor) = self.slide_mut_and_cursor();
+        let offset = slide.offset();
+
+        slide.advance(amount);
+        *cursor = slide.offset();
+
+        let (consumed, _) = slide.into_split();
+
+        &mut consumed[offset..]
+    }
+
+    /// Attempt to rewind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(rewound)` upon success, where `rewound` is the region the cursor moved over.
+    ///
+    /// - `Err(error)` if there are less than `amount` consumed elements.
+    ///
+    ///   The cursor is left untouched upon failure.
+    #[inline]
+    #[track_caller]
+    pub fn try_rewind(
+        &mut self,
+        amount: usize,
+    ) -> Result<&mut [T], SplitError<[T]>> {
+        let (mut slide, cursor) = self.slide_mut_and_cursor();
+
+        slide.try_rewind(amount)?;
+        *cursor = slide.offset();
+
+        let (_, remaining) = slide.into_split();
+
+        Ok(&mut remaining[..amount])
+    }
+
+    /// Rewind the cursor by `amount` elements.
+    ///
+    /// # Returns
+    ///
+    /// Returns the region the cursor moved over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `amount` consumed elements.
+    #[inline]
+    #[track_caller]
+    pub fn rewind(
+        &mut self,
+        amount: usize,
+    ) -> &mut [T] {
+        let (mut slide, cursor) = self.slide_mut_and_cursor();
+
+        slide.rewind(amount);
+        *cursor = slide.offset();
+
+        let (_, remaining) = slide.into_split();
+
+        &mut remaining[..amount]
+    }
+}
+
+impl<T, const N: usize> Drop for StackSlide<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The first `len` elements are initialized, and are never used again.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+impl<T, const N: usize> Default for StackSlide<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        StackSlide::new()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for StackSlide<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("StackSlide")
+            .field("consumed", &self.consumed())
+            .field("remaining", &self.remaining())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+/// An error that occurs when writing to a [`StackSlide`] would exceed its capacity.
+///
+/// Optionally holds the element that could not be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CapacityError<T = ()> {
+    /// The element that could not be written.
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Create a new [`CapacityError`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(element: T) -> CapacityError<T> {
+        CapacityError { element }
+    }
+
+    /// Borrow the element that could not be written.
+    #[inline(always)]
+    #[must_use]
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Get the element that could not be written.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_element(self) -> T {
+        self.element
+    }
+
+    /// Discard the element that could not be written.
+    #[inline(always)]
+    #[must_use]
+    pub fn simplify(self) -> CapacityError {
+        CapacityError::new(())
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+impl<T> Error for CapacityError<T> where T: fmt::Debug {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_slide_mut_writes_back_cursor() {
+        let mut stack = StackSlide::<u8, 4>::new();
+        stack.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        let advanced = stack.with_slide_mut(|slide| slide.advance(2).to_vec());
+
+        assert_eq!(advanced, [1, 2]);
+        assert_eq!(stack.offset(), 2);
+        assert_eq!(stack.remaining(), [3]);
+    }
+
+    #[test]
+    fn with_slide_mut_allows_shrinking() {
+        let mut stack = StackSlide::<u8, 8>::new();
+        stack.extend_from_slice(&[1, 1, 2, 2, 3]).unwrap();
+
+        let len = stack.with_slide_mut(|slide| {
+            slide.advance(1);
+            slide.dedup_remaining()
+        });
+
+        assert_eq!(len, 3);
+        assert_eq!(stack.offset(), 1);
+        assert_eq!(stack.remaining()[..len], [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic = "the slide was replaced with one over a different source"]
+    fn with_slide_mut_rejects_foreign_slide() {
+        let mut stack = StackSlide::<u8, 4>::new();
+        stack.push(0).unwrap();
+
+        stack.with_slide_mut(|slide| {
+            let foreign = Box::leak(vec![0u8; 1000].into_boxed_slice());
+
+            *slide = SlideMut::with_offset(foreign, 900);
+        });
+    }
+
+    #[test]
+    #[should_panic = "the slide was replaced with one over a different source"]
+    fn with_slide_mut_rejects_shorter_slide() {
+        let mut stack = StackSlide::<u8, 4>::new();
+        stack.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        stack.with_slide_mut(|slide| *slide = SlideMut::new(&mut []));
+    }
+}
@@ -1,6 +1,10 @@
 use core::ops::Range;
 
-use crate::macros::{assert_unchecked, unreachable_unchecked};
+use crate::{
+    macros::{assert_unchecked, unreachable_unchecked},
+    slice::split_at_unchecked,
+    util::min_usize,
+};
 
 /// Returns whether a given byte is a UTF-8 character boundary.
 ///
@@ -15,6 +19,46 @@ pub const fn is_utf8_char_boundary(byte: u8) -> bool {
     matches!(byte, 0x00..=0x7F | 0xC0..=0xFF)
 }
 
+/// Returns whether `index` lies on a character boundary of `s`.
+///
+/// Like [`str::is_char_boundary`], both `0` and `s.len()` are considered boundaries,
+/// and any index greater than `s.len()` is not.
+#[inline(always)]
+#[must_use]
+pub const fn is_char_boundary(
+    s: &str,
+    index: usize,
+) -> bool {
+    if index == 0 || index == s.len() {
+        true
+    } else if index < s.len() {
+        is_utf8_char_boundary(s.as_bytes()[index])
+    } else {
+        false
+    }
+}
+
+/// Split `s` at byte `index`, provided it lies on a character boundary.
+///
+/// # Returns
+///
+/// - `Some((head, tail))` upon success.
+///
+/// - `None` if `index > s.len()` or `index` does not lie on a character boundary.
+#[inline]
+#[must_use]
+pub const fn split_at_boundary_checked(
+    s: &str,
+    index: usize,
+) -> Option<(&str, &str)> {
+    if is_char_boundary(s, index) {
+        // SAFETY: We just checked that `index` is in bounds and lies on a character boundary.
+        Some(unsafe { split_at_unchecked(s, index) })
+    } else {
+        None
+    }
+}
+
 /// Why? Why not? Mainly just to flex my stupidity when, in reality, it does not matter like, at all.
 ///
 /// See [`str::floor_char_boundary`] for details.
@@ -91,8 +135,159 @@ pub const fn floor_char_boundary(
     new_index
 }
 
+/// The ceiling counterpart to [`floor_char_boundary`].
+///
+/// Returns the smallest character boundary in `s` that is `>= index`, or `s.len()`
+/// if `index >= s.len()`.
+///
+/// See [`str::ceil_char_boundary`] for details.
+#[cfg_attr(not(debug_assertions), inline(always))]
+#[must_use]
+pub const fn ceil_char_boundary(
+    s: &str,
+    index: usize,
+) -> usize {
+    let new_index = if index >= s.len() {
+        s.len()
+    } else {
+        // NOTE: We need to find the leftmost byte in the range `index..min(index + 4, s.len())` that is a
+        //       UTF-8 character boundary. If there is none, then the boundary is the end of `s`.
+        let range = index..min_usize(index.saturating_add(4), s.len());
+        let Range { mut start, end } = {
+            // SAFETY: We know that `index < s.len()`.
+            let start = unsafe { s.as_ptr().add(range.start) };
+            // SAFETY: We know that `range.end <= s.len()`.
+            let end = unsafe { s.as_ptr().add(range.end) };
+
+            // SAFETY: We know that `start < end`.
+            start..end
+        };
+
+        // SAFETY: We only ever scan at *most* 4 bytes, see `floor_char_boundary` for why this hint exists.
+        unsafe { assert_unchecked!(end.offset_from_unsigned(start) <= 4, "`end - start > 4`") };
+
+        'block: {
+            // SAFETY: We know that `start` and `end` are derived from the same allocated object. We also ensure
+            //         that `start <= end` is always upheld, so there is *zero* chance for overflow.
+            while unsafe { end.offset_from_unsigned(start) > 0 } {
+                // SAFETY: We know that `end - start >= 1`, so `start` points to a byte within `s`.
+                let value = unsafe { start.read() };
+
+                if is_utf8_char_boundary(value) {
+                    // SAFETY: We know that `start` is derived from `s` and is never before it.
+                    break 'block unsafe { start.offset_from_unsigned(s.as_ptr()) };
+                }
+
+                // SAFETY: We know that `end - start >= 1`.
+                start = unsafe { start.add(1) };
+            }
+
+            // SAFETY: In valid UTF-8, a character is at *most* 4 bytes long, so if we failed to find a boundary
+            //         within the scanned bytes, then the scan must have reached the end of `s`. Otherwise `s`
+            //         is ill-formed, and the existence of it is undefined behavior anyways.
+            unsafe { assert_unchecked!(range.end == s.len(), "`s` contains ill-formed UTF-8") };
+
+            s.len()
+        }
+    };
+
+    // SAFETY: We know that `new_index` is never greater than the length of `s`.
+    unsafe { assert_unchecked!(new_index <= s.len(), "`new_index > s.len()`") };
+    // SAFETY: We know that `new_index` is never less than `index`, unless `index` is out of bounds.
+    unsafe { assert_unchecked!(new_index >= index || index > s.len(), "`new_index < index`") };
+
+    new_index
+}
+
 #[doc(inline)]
 pub use crate::slice::str::{StrAsElemsError, StrSplitError};
 
 #[doc(inline)]
 pub use ::core::str::Utf8Error;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check every property of [`ceil_char_boundary`] for `s` at `index`.
+    #[track_caller]
+    fn check_ceil(
+        s: &str,
+        index: usize,
+    ) -> usize {
+        let result = ceil_char_boundary(s, index);
+
+        assert!(result <= s.len(), "{s:?} at {index}: {result} > len");
+        assert!(s.is_char_boundary(result), "{s:?} at {index}: {result}");
+
+        if index <= s.len() {
+            assert!(result >= index, "{s:?} at {index}: {result} < index");
+            assert!(
+                (index..result).all(|i| !s.is_char_boundary(i)),
+                "{s:?} at {index}: {result} is not the smallest boundary"
+            );
+        } else {
+            assert_eq!(result, s.len(), "{s:?} at {index}");
+        }
+
+        result
+    }
+
+    #[test]
+    fn ceil_char_boundary_multi_byte() {
+        // NOTE: A 1-, 2-, 3- and 4-byte character, each followed by a single byte.
+        for ch in ['a', 'é', '€', '🦀'] {
+            let mut buf = [0; 8];
+            let encoded = ch.encode_utf8(&mut buf[1..]).len();
+            buf[0] = b'x';
+            buf[encoded + 1] = b'y';
+
+            let s = core::str::from_utf8(&buf[..encoded + 2]).unwrap();
+
+            assert_eq!(check_ceil(s, 0), 0);
+            assert_eq!(check_ceil(s, 1), 1);
+
+            for index in 2..=encoded {
+                assert_eq!(check_ceil(s, index), encoded + 1, "{s:?} at {index}");
+            }
+
+            assert_eq!(check_ceil(s, encoded + 1), encoded + 1);
+            assert_eq!(check_ceil(s, encoded + 2), encoded + 2);
+        }
+    }
+
+    #[test]
+    fn ceil_char_boundary_near_end() {
+        // NOTE: Multi-byte characters that end the string, so the scan hits the end.
+        for s in ["é", "a€", "ab🦀", "🦀"] {
+            for index in 0..=s.len() + 2 {
+                check_ceil(s, index);
+            }
+
+            assert_eq!(ceil_char_boundary(s, s.len() - 1), s.len());
+        }
+    }
+
+    #[test]
+    fn ceil_char_boundary_out_of_bounds() {
+        assert_eq!(check_ceil("", 0), 0);
+        assert_eq!(check_ceil("", 1), 0);
+        assert_eq!(check_ceil("a€", 4), 4);
+        assert_eq!(check_ceil("a€", 5), 4);
+        assert_eq!(check_ceil("a€", usize::MAX), 4);
+    }
+
+    #[test]
+    fn ceil_char_boundary_every_index() {
+        let s = "a\u{e9}\u{20ac}\u{1f980}b\u{1f980}\u{e9}";
+
+        for index in 0..=s.len() + 1 {
+            check_ceil(s, index);
+        }
+    }
+
+    #[test]
+    fn ceil_char_boundary_const() {
+        const { assert!(ceil_char_boundary("a\u{20ac}", 2) == 4) };
+    }
+}
@@ -98,3 +98,13 @@ pub(crate) const fn cmp_usize(
         (true, true) => unreachable!(),
     }
 }
+
+/// Returns the minimum of two [`prim@usize`]s.
+#[inline(always)]
+#[must_use]
+pub(crate) const fn min_usize(
+    lhs: usize,
+    rhs: usize,
+) -> usize {
+    if lhs < rhs { lhs } else { rhs }
+}